@@ -0,0 +1,294 @@
+//! # ACMI エクスポーター
+//!
+//! Tacview互換のテキスト形式ACMIファイル（`text/acmi/tacview` 2.2）へ
+//! 交戦全体のリプレイを書き出します。
+//!
+//! - ターゲット・ミサイル・ランチャー・センサー・指揮所・防護対象をオブジェクトとして出力
+//! - 移動体はステップごとに位置（経度・緯度・高度）と姿勢（ロール・ピッチ・方位）を出力
+//! - 終了したオブジェクトは`MissileEndReason` / `AgentStatus`に応じたイベントを付けて削除
+//!
+//! ローカル座標（X=東, Y=北, Z=上）は、設定された基準点を原点とする
+//! ENU座標として測地座標に変換されます（高度はローカルZ座標を保持）。
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use tracing::info;
+
+use crate::export::ISimulationObserver;
+use crate::models::{
+    AgentStatus, Attitude3D, GeodeticPosition, GeodeticReference, IAgent, MissileEndReason, Position3D,
+    geodetic::yaw_to_heading,
+};
+use crate::simulation::{SimulationEngine, SimulationEvent};
+
+/// ACMI出力設定
+#[derive(Debug, Clone)]
+pub struct AcmiConfig {
+    /// 出力ファイルパス
+    pub path: PathBuf,
+    /// ローカル座標原点の測地座標
+    pub origin: GeodeticPosition,
+}
+
+/// ACMIオブジェクトの種別
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ObjectKind {
+    Target,
+    Missile,
+    Launcher,
+    Sensor,
+    CommandPost,
+    DefendedAsset,
+}
+
+impl ObjectKind {
+    /// Tacviewの種別タグ
+    fn type_tags(self) -> &'static str {
+        match self {
+            ObjectKind::Target => "Air+FixedWing",
+            ObjectKind::Missile => "Weapon+Missile",
+            ObjectKind::Launcher => "Ground+Static+AntiAircraft",
+            ObjectKind::Sensor => "Ground+Static+Building",
+            ObjectKind::CommandPost => "Ground+Static+Building",
+            ObjectKind::DefendedAsset => "Ground+Static+Building",
+        }
+    }
+
+    /// 陣営名と表示色
+    fn coalition(self) -> (&'static str, &'static str) {
+        match self {
+            ObjectKind::Target => ("Enemies", "Red"),
+            _ => ("Allies", "Blue"),
+        }
+    }
+}
+
+/// ミサイル終了理由に対応するACMIイベント（イベント種別, 説明）
+///
+/// # 引数
+///
+/// * `reason` - ミサイル終了理由
+pub fn missile_end_event(reason: Option<MissileEndReason>) -> (&'static str, &'static str) {
+    match reason {
+        Some(MissileEndReason::Hit) => ("Destroyed", "命中"),
+        Some(MissileEndReason::SelfDestruct) => ("Destroyed", "自爆"),
+        Some(MissileEndReason::TargetLost) => ("Destroyed", "目標消失により自爆"),
+        Some(MissileEndReason::OutOfBounds) => ("LeftArea", "領域外"),
+        None => ("Timeout", "終了"),
+    }
+}
+
+/// ターゲット終了状態に対応するACMIイベント（イベント種別, 説明）
+///
+/// # 引数
+///
+/// * `status` - ターゲット終了時の状態
+pub fn target_end_event(status: AgentStatus) -> (&'static str, &'static str) {
+    match status {
+        AgentStatus::Destroyed => ("Destroyed", "撃破"),
+        AgentStatus::Reached => ("LeftArea", "指揮所到達"),
+        AgentStatus::Inactive | AgentStatus::SelfDestruct => ("LeftArea", "領域外"),
+        AgentStatus::Active => ("Timeout", "終了"),
+    }
+}
+
+/// ACMIファイル出力エクスポーター
+pub struct AcmiExporter {
+    config: AcmiConfig,
+    writer: BufWriter<File>,
+    reference: GeodeticReference,
+    /// エージェントID → ACMIオブジェクトID
+    object_ids: HashMap<String, u64>,
+    frames_written: u64,
+}
+
+/// ACMIオブジェクトIDの開始値（0はグローバルオブジェクト）
+const FIRST_OBJECT_ID: u64 = 0x100;
+
+impl AcmiExporter {
+    /// 新しいACMIエクスポーターを作成し、出力ファイルを開きます
+    ///
+    /// # 引数
+    ///
+    /// * `config` - ACMI出力設定
+    ///
+    /// # 戻り値
+    ///
+    /// 作成されたエクスポーター、ファイル作成に失敗した場合はエラー
+    pub fn new(config: AcmiConfig) -> std::io::Result<Self> {
+        let writer = BufWriter::new(File::create(&config.path)?);
+        Ok(Self {
+            reference: GeodeticReference::new(config.origin),
+            config,
+            writer,
+            object_ids: HashMap::new(),
+            frames_written: 0,
+        })
+    }
+
+    /// エージェントIDに対応するオブジェクトIDを取得
+    ///
+    /// 戻り値の2番目は新規採番された場合にtrue
+    fn object_id(&mut self, agent_id: &str) -> (u64, bool) {
+        if let Some(id) = self.object_ids.get(agent_id) {
+            return (*id, false);
+        }
+        let id = FIRST_OBJECT_ID + self.object_ids.len() as u64;
+        self.object_ids.insert(agent_id.to_string(), id);
+        (id, true)
+    }
+
+    /// オブジェクトの状態行を出力
+    ///
+    /// 初出のオブジェクトには名称・種別・陣営を付与します。
+    fn write_object(
+        &mut self,
+        agent_id: &str,
+        kind: ObjectKind,
+        position: &Position3D,
+        attitude: &Attitude3D,
+    ) -> std::io::Result<()> {
+        let (object_id, is_new) = self.object_id(agent_id);
+        let geodetic = self.reference.local_to_geodetic(position);
+        let longitude = geodetic.longitude_deg - self.config.origin.longitude_deg;
+        let latitude = geodetic.latitude_deg - self.config.origin.latitude_deg;
+
+        write!(
+            self.writer,
+            "{:x},T={:.7}|{:.7}|{:.1}|{:.1}|{:.1}|{:.1}",
+            object_id,
+            longitude,
+            latitude,
+            geodetic.altitude_m,
+            attitude.roll,
+            attitude.pitch,
+            yaw_to_heading(attitude.yaw),
+        )?;
+        if is_new {
+            let (coalition, color) = kind.coalition();
+            write!(
+                self.writer,
+                ",Name={},Type={},Coalition={},Color={}",
+                agent_id,
+                kind.type_tags(),
+                coalition,
+                color,
+            )?;
+        }
+        writeln!(self.writer)
+    }
+
+    /// 終了イベントを出力し、オブジェクトを削除
+    fn write_removal(
+        &mut self,
+        agent_id: &str,
+        related_id: Option<&str>,
+        (event, text): (&str, &str),
+    ) -> std::io::Result<()> {
+        let Some(object_id) = self.object_ids.get(agent_id).copied() else {
+            return Ok(());
+        };
+        let related = related_id
+            .and_then(|id| self.object_ids.get(id))
+            .map(|id| format!("|{:x}", id))
+            .unwrap_or_default();
+        writeln!(self.writer, "0,Event={}|{:x}{}|{} {}", event, object_id, related, agent_id, text)?;
+        writeln!(self.writer, "-{:x}", object_id)
+    }
+
+    /// 静止オブジェクト（指揮所・防護対象・センサー・ランチャー）の出力
+    fn write_static_objects(&mut self, engine: &SimulationEngine) -> std::io::Result<()> {
+        let still = Attitude3D::new(0.0, 0.0, 0.0);
+        let command_post = &engine.command_post;
+        self.write_object(&command_post.get_id(), ObjectKind::CommandPost, &command_post.position, &still)?;
+        for asset in &engine.defended_assets {
+            self.write_object(&asset.id, ObjectKind::DefendedAsset, &asset.position, &still)?;
+        }
+        for sensor in &engine.sensor_network.sensors {
+            self.write_object(&sensor.get_id(), ObjectKind::Sensor, &sensor.position, &still)?;
+        }
+        for launcher in &engine.launchers {
+            self.write_object(&launcher.get_id(), ObjectKind::Launcher, &launcher.position, &still)?;
+        }
+        Ok(())
+    }
+}
+
+impl ISimulationObserver for AcmiExporter {
+    fn on_start(&mut self, engine: &SimulationEngine) -> std::io::Result<()> {
+        info!(path = %self.config.path.display(), "ACMI出力を開始します");
+
+        writeln!(self.writer, "FileType=text/acmi/tacview")?;
+        writeln!(self.writer, "FileVersion=2.2")?;
+        writeln!(self.writer, "0,DataSource=defsim")?;
+        writeln!(self.writer, "0,Title={}", engine.scenario_config.meta.name)?;
+        writeln!(self.writer, "0,ReferenceLongitude={}", self.config.origin.longitude_deg)?;
+        writeln!(self.writer, "0,ReferenceLatitude={}", self.config.origin.latitude_deg)?;
+        writeln!(self.writer, "#{:.2}", engine.current_time)?;
+        self.write_static_objects(engine)
+    }
+
+    fn on_step(&mut self, engine: &SimulationEngine) -> std::io::Result<()> {
+        writeln!(self.writer, "#{:.2}", engine.current_time)?;
+        self.frames_written += 1;
+
+        // 移動体: 毎ステップ出力
+        for target in engine.targets.iter().filter(|t| t.is_active()) {
+            let attitude = Attitude3D::from_velocity(&target.velocity);
+            self.write_object(&target.id, ObjectKind::Target, &target.position, &attitude)?;
+        }
+        for missile in engine.missiles.iter().filter(|m| m.is_active()) {
+            self.write_object(&missile.id, ObjectKind::Missile, &missile.position, &missile.attitude)?;
+        }
+
+        // 終了イベント: 最終位置を出力してから削除
+        for event in &engine.events {
+            match event {
+                SimulationEvent::MissileEnded { missile } => {
+                    self.write_object(&missile.id, ObjectKind::Missile, &missile.position, &missile.attitude)?;
+                    let related = (missile.end_reason == Some(MissileEndReason::Hit)).then_some(missile.target_id.as_str());
+                    self.write_removal(&missile.id, related, missile_end_event(missile.end_reason))?;
+                }
+                SimulationEvent::TargetEnded { target_id, status } => {
+                    if let Some(target) = engine.targets.iter().find(|t| &t.id == target_id) {
+                        let attitude = Attitude3D::from_velocity(&target.velocity);
+                        self.write_object(&target.id, ObjectKind::Target, &target.position, &attitude)?;
+                    }
+                    self.write_removal(target_id, None, target_end_event(*status))?;
+                }
+                SimulationEvent::AssetDisabled { asset_id, target_id } => {
+                    self.write_removal(asset_id, Some(target_id), ("Destroyed", "制圧攻撃により無力化"))?;
+                }
+                SimulationEvent::MissileLaunched { .. } => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn on_finish(&mut self, _engine: &SimulationEngine) -> std::io::Result<()> {
+        self.writer.flush()?;
+        info!(
+            "ACMI出力完了: {} （フレーム数: {}, オブジェクト数: {}）",
+            self.config.path.display(),
+            self.frames_written,
+            self.object_ids.len()
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_end_event_mapping() {
+        assert_eq!(missile_end_event(Some(MissileEndReason::Hit)).0, "Destroyed");
+        assert_eq!(missile_end_event(Some(MissileEndReason::OutOfBounds)).0, "LeftArea");
+        assert_eq!(target_end_event(AgentStatus::Destroyed).0, "Destroyed");
+        assert_eq!(target_end_event(AgentStatus::Reached).0, "LeftArea");
+    }
+}
@@ -0,0 +1,636 @@
+//! # DIS エクスポーター
+//!
+//! IEEE 1278.1 DIS（Distributed Interactive Simulation）形式のPDUを
+//! UDPで送信し、既存のシミュレーションツールと連接します。
+//!
+//! - **Entity State PDU**: 全エージェントの位置・速度・姿勢
+//! - **Fire PDU**: ミサイル発射
+//! - **Detonation PDU**: ミサイル終了（`MissileEndReason`に応じた結果コード）
+//!
+//! シミュレーションのローカル座標（X=東, Y=北, Z=上）は、設定された基準点を
+//! 原点とするENU座標として地心直交座標（ECEF）に変換されます（高度はローカルZ座標を保持）。
+
+use std::collections::HashMap;
+use std::net::{SocketAddr, UdpSocket};
+use tracing::{debug, info};
+
+use crate::export::ISimulationObserver;
+use crate::models::{
+    AgentStatus, GeodeticPosition, GeodeticReference, IAgent, Missile, MissileEndReason,
+    Position3D, Velocity3D, Attitude3D,
+    geodetic::yaw_to_heading,
+};
+use crate::simulation::{SimulationEngine, SimulationEvent};
+
+/// DISプロトコルバージョン（IEEE 1278.1-1995）
+const PROTOCOL_VERSION: u8 = 6;
+/// PDU種別: Entity State
+const PDU_TYPE_ENTITY_STATE: u8 = 1;
+/// PDU種別: Fire
+const PDU_TYPE_FIRE: u8 = 2;
+/// PDU種別: Detonation
+const PDU_TYPE_DETONATION: u8 = 3;
+/// プロトコルファミリ: Entity Information/Interaction
+const FAMILY_ENTITY_INFORMATION: u8 = 1;
+/// プロトコルファミリ: Warfare
+const FAMILY_WARFARE: u8 = 2;
+
+/// Entity State PDU長（アーティキュレーションパラメータなし）
+pub const ENTITY_STATE_PDU_LENGTH: usize = 144;
+/// Fire PDU長
+pub const FIRE_PDU_LENGTH: usize = 96;
+/// Detonation PDU長（アーティキュレーションパラメータなし）
+pub const DETONATION_PDU_LENGTH: usize = 104;
+
+/// 勢力ID: 友軍
+const FORCE_FRIENDLY: u8 = 1;
+/// 勢力ID: 敵軍
+const FORCE_OPPOSING: u8 = 2;
+
+/// 外観ビット: 破壊状態（Damage = Destroyed）
+const APPEARANCE_DESTROYED: u32 = 3 << 3;
+/// 外観ビット: 非アクティブ化
+const APPEARANCE_DEACTIVATED: u32 = 1 << 23;
+
+/// 静止エンティティ（指揮所・センサー・ランチャー）のハートビート間隔（秒）
+const STATIC_HEARTBEAT_S: f64 = 5.0;
+
+/// DISエンティティ種別（kind, domain, country, category, subcategory, specific, extra）
+type EntityType = [u8; 8];
+
+/// 敵ターゲット: Platform / Air
+const ENTITY_TYPE_TARGET: EntityType = [1, 2, 0, 0, 1, 0, 0, 0];
+/// 迎撃ミサイル: Munition / Anti-Air / Guided
+const ENTITY_TYPE_MISSILE: EntityType = [2, 1, 0, 0, 1, 0, 0, 0];
+/// 地上設備（指揮所・センサー・ランチャー）: Platform / Land
+const ENTITY_TYPE_GROUND: EntityType = [1, 1, 0, 0, 0, 0, 0, 0];
+
+/// DIS出力設定
+#[derive(Debug, Clone)]
+pub struct DisConfig {
+    /// 送信先アドレス（ユニキャストまたはブロードキャスト）
+    pub address: SocketAddr,
+    /// 演習ID
+    pub exercise_id: u8,
+    /// サイトID
+    pub site_id: u16,
+    /// アプリケーションID
+    pub application_id: u16,
+    /// ローカル座標原点の測地座標
+    pub origin: GeodeticPosition,
+}
+
+/// Detonation PDUの結果コード
+///
+/// # 引数
+///
+/// * `reason` - ミサイル終了理由
+///
+/// # 戻り値
+///
+/// DIS Detonation Result 列挙値
+pub fn detonation_result(reason: Option<MissileEndReason>) -> u8 {
+    match reason {
+        Some(MissileEndReason::Hit) => 1,          // Entity Impact
+        Some(MissileEndReason::SelfDestruct) => 5, // Detonation
+        Some(MissileEndReason::TargetLost) => 5,   // Detonation
+        Some(MissileEndReason::OutOfBounds) => 6,  // None or No Detonation (Dud)
+        None => 0,                                 // Other
+    }
+}
+
+/// シミュレーション時刻をDIS相対タイムスタンプに変換
+///
+/// 1時間を2^31分割した単位で表し、最下位ビット0は相対時刻を示します。
+fn dis_timestamp(time_s: f64) -> u32 {
+    let fraction = time_s.rem_euclid(3600.0) / 3600.0;
+    let units = (fraction * 2_147_483_648.0) as u32 & 0x7FFF_FFFF;
+    units << 1
+}
+
+/// ビッグエンディアンでPDUを組み立てるライター
+struct PduWriter {
+    buffer: Vec<u8>,
+}
+
+impl PduWriter {
+    fn new(pdu_type: u8, family: u8, exercise_id: u8, timestamp: u32, length: usize) -> Self {
+        let mut writer = Self { buffer: Vec::with_capacity(length) };
+        writer.u8(PROTOCOL_VERSION);
+        writer.u8(exercise_id);
+        writer.u8(pdu_type);
+        writer.u8(family);
+        writer.u32(timestamp);
+        writer.u16(length as u16);
+        writer.u16(0); // パディング
+        writer
+    }
+
+    fn u8(&mut self, value: u8) {
+        self.buffer.push(value);
+    }
+
+    fn u16(&mut self, value: u16) {
+        self.buffer.extend_from_slice(&value.to_be_bytes());
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.buffer.extend_from_slice(&value.to_be_bytes());
+    }
+
+    fn f32(&mut self, value: f64) {
+        self.buffer.extend_from_slice(&(value as f32).to_be_bytes());
+    }
+
+    fn f64(&mut self, value: f64) {
+        self.buffer.extend_from_slice(&value.to_be_bytes());
+    }
+
+    fn zeros(&mut self, count: usize) {
+        self.buffer.resize(self.buffer.len() + count, 0);
+    }
+
+    fn entity_id(&mut self, id: (u16, u16, u16)) {
+        self.u16(id.0);
+        self.u16(id.1);
+        self.u16(id.2);
+    }
+
+    fn entity_type(&mut self, entity_type: &EntityType) {
+        // kind, domain, country(u16), category, subcategory, specific, extra
+        self.u8(entity_type[0]);
+        self.u8(entity_type[1]);
+        self.u16(u16::from_be_bytes([entity_type[2], entity_type[3]]));
+        self.u8(entity_type[4]);
+        self.u8(entity_type[5]);
+        self.u8(entity_type[6]);
+        self.u8(entity_type[7]);
+    }
+
+    fn finish(self) -> Vec<u8> {
+        self.buffer
+    }
+}
+
+/// Entity State PDUの内容
+struct EntityState<'a> {
+    entity_id: (u16, u16, u16),
+    force_id: u8,
+    entity_type: EntityType,
+    marking: &'a str,
+    position: Position3D,
+    velocity: Velocity3D,
+    attitude: Attitude3D,
+    appearance: u32,
+}
+
+/// DIS PDU送信エクスポーター
+pub struct DisExporter {
+    config: DisConfig,
+    socket: UdpSocket,
+    reference: GeodeticReference,
+    /// エージェントID → DISエンティティ番号
+    entity_numbers: HashMap<String, u16>,
+    /// ミサイルID → (発射ランチャーID, イベント番号)
+    fire_events: HashMap<String, (String, u16)>,
+    event_counter: u16,
+    last_static_heartbeat: f64,
+    pdus_sent: u64,
+}
+
+impl DisExporter {
+    /// 新しいDISエクスポーターを作成し、送信用UDPソケットを開きます
+    ///
+    /// # 引数
+    ///
+    /// * `config` - DIS出力設定
+    ///
+    /// # 戻り値
+    ///
+    /// 作成されたエクスポーター、ソケット作成に失敗した場合はエラー
+    pub fn new(config: DisConfig) -> std::io::Result<Self> {
+        let bind_address = if config.address.is_ipv4() { "0.0.0.0:0" } else { "[::]:0" };
+        let socket = UdpSocket::bind(bind_address)?;
+        socket.set_broadcast(true)?;
+
+        Ok(Self {
+            reference: GeodeticReference::new(config.origin),
+            config,
+            socket,
+            entity_numbers: HashMap::new(),
+            fire_events: HashMap::new(),
+            event_counter: 0,
+            last_static_heartbeat: f64::NEG_INFINITY,
+            pdus_sent: 0,
+        })
+    }
+
+    /// エージェントIDに対応するDISエンティティIDを取得（未登録なら採番）
+    fn entity_id(&mut self, agent_id: &str) -> (u16, u16, u16) {
+        let next_number = self.entity_numbers.len() as u16 + 1;
+        let number = *self.entity_numbers.entry(agent_id.to_string()).or_insert(next_number);
+        (self.config.site_id, self.config.application_id, number)
+    }
+
+    fn send(&mut self, pdu: &[u8]) -> std::io::Result<()> {
+        self.socket.send_to(pdu, self.config.address)?;
+        self.pdus_sent += 1;
+        Ok(())
+    }
+
+    /// Entity State PDUを組み立て
+    fn encode_entity_state(&self, state: &EntityState, time_s: f64) -> Vec<u8> {
+        let mut writer = PduWriter::new(
+            PDU_TYPE_ENTITY_STATE,
+            FAMILY_ENTITY_INFORMATION,
+            self.config.exercise_id,
+            dis_timestamp(time_s),
+            ENTITY_STATE_PDU_LENGTH,
+        );
+
+        let location = self.reference.local_to_geodetic(&state.position).to_ecef();
+        let (vx, vy, vz) = self.reference.enu_velocity_to_ecef(&state.velocity);
+        let (psi, theta, phi) = self.reference.local_attitude_to_ecef_euler(
+            &state.position,
+            yaw_to_heading(state.attitude.yaw),
+            state.attitude.pitch,
+            state.attitude.roll,
+        );
+
+        writer.entity_id(state.entity_id);
+        writer.u8(state.force_id);
+        writer.u8(0); // アーティキュレーションパラメータ数
+        writer.entity_type(&state.entity_type);
+        writer.entity_type(&state.entity_type); // 代替エンティティ種別
+        writer.f32(vx);
+        writer.f32(vy);
+        writer.f32(vz);
+        writer.f64(location.x);
+        writer.f64(location.y);
+        writer.f64(location.z);
+        writer.f32(psi);
+        writer.f32(theta);
+        writer.f32(phi);
+        writer.u32(state.appearance);
+        writer.u8(2); // 推測航法: DRM(F, P, W)
+        writer.zeros(15); // 推測航法その他パラメータ
+        writer.zeros(12); // 線形加速度
+        writer.zeros(12); // 角速度
+        writer.u8(1); // マーキング文字セット: ASCII
+        let mut marking = [0u8; 11];
+        for (slot, byte) in marking.iter_mut().zip(state.marking.bytes()) {
+            *slot = byte;
+        }
+        for byte in marking {
+            writer.u8(byte);
+        }
+        writer.u32(0); // 能力フラグ
+
+        writer.finish()
+    }
+
+    fn send_entity_state(&mut self, state: EntityState, time_s: f64) -> std::io::Result<()> {
+        let pdu = self.encode_entity_state(&state, time_s);
+        self.send(&pdu)
+    }
+
+    /// 静止エンティティ（指揮所・センサー・ランチャー）のEntity State送信
+    fn send_static_entities(&mut self, engine: &SimulationEngine) -> std::io::Result<()> {
+        let mut statics = vec![(engine.command_post.get_id(), engine.command_post.position, engine.command_post.status)];
+        statics.extend(engine.sensor_network.sensors.iter().map(|s| (s.get_id(), s.position, s.status)));
+        statics.extend(engine.launchers.iter().map(|l| (l.get_id(), l.position, l.status)));
+
+        for (id, position, status) in statics {
+            let state = EntityState {
+                entity_id: self.entity_id(&id),
+                force_id: FORCE_FRIENDLY,
+                entity_type: ENTITY_TYPE_GROUND,
+                marking: &id,
+                position,
+                velocity: Velocity3D::new(0.0, 0.0, 0.0),
+                attitude: Attitude3D::new(0.0, 0.0, 0.0),
+                appearance: if status == AgentStatus::Destroyed { APPEARANCE_DESTROYED } else { 0 },
+            };
+            self.send_entity_state(state, engine.current_time)?;
+        }
+        Ok(())
+    }
+
+    fn send_missile_state(&mut self, missile: &Missile, appearance: u32, time_s: f64) -> std::io::Result<()> {
+        let state = EntityState {
+            entity_id: self.entity_id(&missile.id),
+            force_id: FORCE_FRIENDLY,
+            entity_type: ENTITY_TYPE_MISSILE,
+            marking: &missile.id,
+            position: missile.position,
+            velocity: missile.velocity,
+            attitude: missile.attitude,
+            appearance,
+        };
+        self.send_entity_state(state, time_s)
+    }
+
+    /// Fire PDUの送信
+    fn send_fire(&mut self, engine: &SimulationEngine, launcher_id: &str, missile: &Missile) -> std::io::Result<()> {
+        let range = engine.targets
+            .iter()
+            .find(|t| t.id == missile.target_id)
+            .map(|t| missile.position.distance_3d(&t.position))
+            .unwrap_or(0.0);
+        let pdu = self.encode_fire(launcher_id, missile, range, engine.current_time);
+        self.send(&pdu)
+    }
+
+    /// Fire PDUを組み立て（発射イベント番号を採番して記録）
+    fn encode_fire(&mut self, launcher_id: &str, missile: &Missile, range: f64, time_s: f64) -> Vec<u8> {
+        self.event_counter = self.event_counter.wrapping_add(1);
+        let event_number = self.event_counter;
+        self.fire_events.insert(missile.id.clone(), (launcher_id.to_string(), event_number));
+
+        let firing_id = self.entity_id(launcher_id);
+        let target_id = self.entity_id(&missile.target_id);
+        let munition_id = self.entity_id(&missile.id);
+        let location = self.reference.local_to_geodetic(&missile.position).to_ecef();
+        let (vx, vy, vz) = self.reference.enu_velocity_to_ecef(&missile.velocity);
+
+        let mut writer = PduWriter::new(
+            PDU_TYPE_FIRE,
+            FAMILY_WARFARE,
+            self.config.exercise_id,
+            dis_timestamp(time_s),
+            FIRE_PDU_LENGTH,
+        );
+        writer.entity_id(firing_id);
+        writer.entity_id(target_id);
+        writer.entity_id(munition_id);
+        writer.entity_id((self.config.site_id, self.config.application_id, event_number));
+        writer.u32(0); // 射撃任務インデックス
+        writer.f64(location.x);
+        writer.f64(location.y);
+        writer.f64(location.z);
+        writer.entity_type(&ENTITY_TYPE_MISSILE);
+        writer.u16(0); // 弾頭
+        writer.u16(0); // 信管
+        writer.u16(1); // 数量
+        writer.u16(0); // 発射レート
+        writer.f32(vx);
+        writer.f32(vy);
+        writer.f32(vz);
+        writer.f32(range);
+
+        writer.finish()
+    }
+
+    /// Detonation PDUの送信
+    fn send_detonation(&mut self, engine: &SimulationEngine, missile: &Missile) -> std::io::Result<()> {
+        let pdu = self.encode_detonation(missile, engine.current_time);
+        self.send(&pdu)
+    }
+
+    /// Detonation PDUを組み立て（対応するFire PDUのイベント番号を使用）
+    fn encode_detonation(&mut self, missile: &Missile, time_s: f64) -> Vec<u8> {
+        let (launcher_id, event_number) = self.fire_events
+            .remove(&missile.id)
+            .unwrap_or_else(|| (String::new(), 0));
+
+        let firing_id = if launcher_id.is_empty() { (0, 0, 0) } else { self.entity_id(&launcher_id) };
+        let target_id = self.entity_id(&missile.target_id);
+        let munition_id = self.entity_id(&missile.id);
+        let location = self.reference.local_to_geodetic(&missile.position).to_ecef();
+        let (vx, vy, vz) = self.reference.enu_velocity_to_ecef(&missile.velocity);
+
+        let mut writer = PduWriter::new(
+            PDU_TYPE_DETONATION,
+            FAMILY_WARFARE,
+            self.config.exercise_id,
+            dis_timestamp(time_s),
+            DETONATION_PDU_LENGTH,
+        );
+        writer.entity_id(firing_id);
+        writer.entity_id(target_id);
+        writer.entity_id(munition_id);
+        writer.entity_id((self.config.site_id, self.config.application_id, event_number));
+        writer.f32(vx);
+        writer.f32(vy);
+        writer.f32(vz);
+        writer.f64(location.x);
+        writer.f64(location.y);
+        writer.f64(location.z);
+        writer.entity_type(&ENTITY_TYPE_MISSILE);
+        writer.u16(0); // 弾頭
+        writer.u16(0); // 信管
+        writer.u16(1); // 数量
+        writer.u16(0); // 発射レート
+        writer.zeros(12); // エンティティ座標系での位置
+        writer.u8(detonation_result(missile.end_reason));
+        writer.u8(0); // アーティキュレーションパラメータ数
+        writer.u16(0); // パディング
+
+        writer.finish()
+    }
+}
+
+impl ISimulationObserver for DisExporter {
+    fn on_start(&mut self, engine: &SimulationEngine) -> std::io::Result<()> {
+        info!(
+            address = %self.config.address,
+            exercise_id = self.config.exercise_id,
+            origin_latitude = self.config.origin.latitude_deg,
+            origin_longitude = self.config.origin.longitude_deg,
+            "DIS出力を開始します"
+        );
+        self.send_static_entities(engine)?;
+        self.last_static_heartbeat = engine.current_time;
+        Ok(())
+    }
+
+    fn on_step(&mut self, engine: &SimulationEngine) -> std::io::Result<()> {
+        let time_s = engine.current_time;
+
+        // イベント: 発射 → Fire PDU、終了 → Detonation PDU
+        for event in &engine.events {
+            match event {
+                SimulationEvent::MissileLaunched { launcher_id, missile } => {
+                    self.send_fire(engine, launcher_id, missile)?;
+                }
+                SimulationEvent::MissileEnded { missile } => {
+                    let appearance = APPEARANCE_DEACTIVATED
+                        | if missile.end_reason == Some(MissileEndReason::Hit) { 0 } else { APPEARANCE_DESTROYED };
+                    self.send_missile_state(missile, appearance, time_s)?;
+                    self.send_detonation(engine, missile)?;
+                }
+                SimulationEvent::TargetEnded { target_id, status } => {
+                    if let Some(target) = engine.targets.iter().find(|t| &t.id == target_id) {
+                        let appearance = APPEARANCE_DEACTIVATED
+                            | if *status == AgentStatus::Destroyed { APPEARANCE_DESTROYED } else { 0 };
+                        let state = EntityState {
+                            entity_id: self.entity_id(&target.id),
+                            force_id: FORCE_OPPOSING,
+                            entity_type: ENTITY_TYPE_TARGET,
+                            marking: &target.id,
+                            position: target.position,
+                            velocity: target.velocity,
+                            attitude: Attitude3D::from_velocity(&target.velocity),
+                            appearance,
+                        };
+                        self.send_entity_state(state, time_s)?;
+                    }
+                }
+                SimulationEvent::AssetDisabled { .. } => {
+                    // 無力化を即時に反映
+                    self.send_static_entities(engine)?;
+                }
+            }
+        }
+
+        // 移動エンティティ: 毎ステップ送信
+        for target in engine.targets.iter().filter(|t| t.is_active()) {
+            let state = EntityState {
+                entity_id: self.entity_id(&target.id),
+                force_id: FORCE_OPPOSING,
+                entity_type: ENTITY_TYPE_TARGET,
+                marking: &target.id,
+                position: target.position,
+                velocity: target.velocity,
+                attitude: Attitude3D::from_velocity(&target.velocity),
+                appearance: 0,
+            };
+            self.send_entity_state(state, time_s)?;
+        }
+        for missile in engine.missiles.iter().filter(|m| m.is_active()) {
+            self.send_missile_state(missile, 0, time_s)?;
+        }
+
+        // 静止エンティティ: ハートビート間隔で送信
+        if time_s - self.last_static_heartbeat >= STATIC_HEARTBEAT_S {
+            self.send_static_entities(engine)?;
+            self.last_static_heartbeat = time_s;
+        }
+
+        Ok(())
+    }
+
+    fn on_finish(&mut self, _engine: &SimulationEngine) -> std::io::Result<()> {
+        debug!(pdus_sent = self.pdus_sent, entities = self.entity_numbers.len(), "DIS出力を終了します");
+        info!("DIS PDU送信数: {}", self.pdus_sent);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn listener_and_exporter() -> (UdpSocket, DisExporter) {
+        let listener = UdpSocket::bind("127.0.0.1:0").unwrap();
+        listener.set_read_timeout(Some(Duration::from_secs(2))).unwrap();
+        let config = DisConfig {
+            address: listener.local_addr().unwrap(),
+            exercise_id: 7,
+            site_id: 1,
+            application_id: 2,
+            origin: GeodeticPosition::new(35.0, 135.0, 0.0),
+        };
+        (listener, DisExporter::new(config).unwrap())
+    }
+
+    #[test]
+    fn test_entity_state_pdu_over_udp() {
+        let (listener, mut exporter) = listener_and_exporter();
+        let entity_id = exporter.entity_id("G001_T001");
+        let state = EntityState {
+            entity_id,
+            force_id: FORCE_OPPOSING,
+            entity_type: ENTITY_TYPE_TARGET,
+            marking: "G001_T001",
+            position: Position3D::new(1000.0, 2000.0, 3000.0),
+            velocity: Velocity3D::new(200.0, 0.0, 0.0),
+            attitude: Attitude3D::new(0.0, 0.0, 0.0),
+            appearance: 0,
+        };
+        exporter.send_entity_state(state, 12.5).unwrap();
+
+        let mut buffer = [0u8; 512];
+        let (length, _) = listener.recv_from(&mut buffer).unwrap();
+        assert_eq!(length, ENTITY_STATE_PDU_LENGTH);
+        assert_eq!(buffer[0], PROTOCOL_VERSION);
+        assert_eq!(buffer[1], 7);
+        assert_eq!(buffer[2], PDU_TYPE_ENTITY_STATE);
+        assert_eq!(u16::from_be_bytes([buffer[8], buffer[9]]) as usize, ENTITY_STATE_PDU_LENGTH);
+        assert_eq!(u16::from_be_bytes([buffer[12], buffer[13]]), 1);
+        assert_eq!(u16::from_be_bytes([buffer[14], buffer[15]]), 2);
+        assert_eq!(buffer[18], FORCE_OPPOSING);
+
+        // 位置はECEFで地球半径程度の大きさになる
+        let x = f64::from_be_bytes(buffer[48..56].try_into().unwrap());
+        let y = f64::from_be_bytes(buffer[56..64].try_into().unwrap());
+        let z = f64::from_be_bytes(buffer[64..72].try_into().unwrap());
+        let radius = (x * x + y * y + z * z).sqrt();
+        assert!(radius > 6_350_000.0 && radius < 6_390_000.0);
+        assert_eq!(&buffer[129..138], b"G001_T001");
+    }
+
+    #[test]
+    fn test_fire_and_detonation_pdus_over_udp() {
+        let (listener, mut exporter) = listener_and_exporter();
+        let mut missile = Missile::new("L001_M001".to_string(), Position3D::new(5000.0, -2000.0, 1500.0), "G001_T001".to_string());
+        missile.velocity = Velocity3D::new(300.0, 0.0, 400.0);
+
+        let fire = exporter.encode_fire("L001", &missile, 12_000.0, 30.0);
+        exporter.send(&fire).unwrap();
+        missile.position = Position3D::new(9000.0, -1000.0, 2500.0);
+        missile.end_reason = Some(MissileEndReason::Hit);
+        let detonation = exporter.encode_detonation(&missile, 42.0);
+        exporter.send(&detonation).unwrap();
+
+        let entity = |buffer: &[u8], offset: usize| -> (u16, u16, u16) {
+            let field = |index: usize| u16::from_be_bytes([buffer[offset + index], buffer[offset + index + 1]]);
+            (field(0), field(2), field(4))
+        };
+        let vector = |buffer: &[u8], offset: usize| -> [f64; 3] {
+            std::array::from_fn(|axis| f64::from_be_bytes(buffer[offset + axis * 8..offset + axis * 8 + 8].try_into().unwrap()))
+        };
+        let ecef = |position: &Position3D| {
+            let location = exporter.reference.local_to_geodetic(position).to_ecef();
+            [location.x, location.y, location.z]
+        };
+
+        // Fire PDU: 発射機・ターゲット・弾体・イベントID、発射位置（ECEF）、射距離
+        let mut buffer = [0u8; 512];
+        let (length, _) = listener.recv_from(&mut buffer).unwrap();
+        assert_eq!(length, FIRE_PDU_LENGTH);
+        assert_eq!(buffer[2], PDU_TYPE_FIRE);
+        assert_eq!(buffer[3], FAMILY_WARFARE);
+        assert_eq!(u16::from_be_bytes([buffer[8], buffer[9]]) as usize, FIRE_PDU_LENGTH);
+        assert_eq!(entity(&buffer, 12), (1, 2, 1));
+        assert_eq!(entity(&buffer, 18), (1, 2, 2));
+        assert_eq!(entity(&buffer, 24), (1, 2, 3));
+        assert_eq!(entity(&buffer, 30), (1, 2, 1));
+        assert_eq!(vector(&buffer, 40), ecef(&Position3D::new(5000.0, -2000.0, 1500.0)));
+        assert_eq!(&buffer[64..72], &ENTITY_TYPE_MISSILE);
+        assert_eq!(u16::from_be_bytes([buffer[76], buffer[77]]), 1);
+        assert_eq!(f32::from_be_bytes(buffer[92..96].try_into().unwrap()), 12_000.0);
+
+        // Detonation PDU: Fire PDUと同じ弾体・イベントID、爆発位置（ECEF）、結果
+        let (length, _) = listener.recv_from(&mut buffer).unwrap();
+        assert_eq!(length, DETONATION_PDU_LENGTH);
+        assert_eq!(buffer[2], PDU_TYPE_DETONATION);
+        assert_eq!(u16::from_be_bytes([buffer[8], buffer[9]]) as usize, DETONATION_PDU_LENGTH);
+        assert_eq!(entity(&buffer, 12), (1, 2, 1));
+        assert_eq!(entity(&buffer, 18), (1, 2, 2));
+        assert_eq!(entity(&buffer, 24), (1, 2, 3));
+        assert_eq!(entity(&buffer, 30), (1, 2, 1));
+        assert_eq!(vector(&buffer, 48), ecef(&missile.position));
+        assert_eq!(&buffer[72..80], &ENTITY_TYPE_MISSILE);
+        assert_eq!(buffer[100], detonation_result(Some(MissileEndReason::Hit)));
+        assert_eq!(buffer[101], 0);
+    }
+
+    #[test]
+    fn test_detonation_result_mapping() {
+        assert_eq!(detonation_result(Some(MissileEndReason::Hit)), 1);
+        assert_eq!(detonation_result(Some(MissileEndReason::SelfDestruct)), 5);
+        assert_eq!(detonation_result(Some(MissileEndReason::OutOfBounds)), 6);
+    }
+}
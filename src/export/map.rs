@@ -0,0 +1,374 @@
+//! # 地図形式エクスポーター
+//!
+//! ブリーフィング用に、シナリオの配置・覆域と実行結果の航跡を
+//! GeoJSON（RFC 7946）またはKML形式で出力します。
+//!
+//! - **点**: 指揮所・防護対象・センサー・ランチャー
+//! - **ポリゴン**: センサー探知範囲（`detection_range`）、指揮所・防護対象の到達判定範囲（`arrival_radius`）
+//! - **線**: ターゲット・ミサイルの航跡（実行後のみ）
+//!
+//! 配置と覆域はシナリオ設定から作成するため、実行前（`--info`）にも出力できます。
+//! 出力形式はファイル拡張子で判定し、`.kml`はKML、それ以外はGeoJSONとします。
+
+use std::fs;
+use std::path::PathBuf;
+use serde_json::{json, Value};
+use tracing::info;
+
+use crate::export::ISimulationObserver;
+use crate::export::track::{TrackKind, TrackRecorder};
+use crate::models::{GeodeticPosition, GeodeticReference, Position3D};
+use crate::scenario::ScenarioConfig;
+use crate::simulation::SimulationEngine;
+
+/// 円ポリゴンの分割数
+const CIRCLE_SEGMENTS: usize = 72;
+/// 航跡の記録間隔（秒）
+const TRACK_SAMPLE_INTERVAL_S: f64 = 1.0;
+
+/// 地図出力形式
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MapFormat {
+    GeoJson,
+    Kml,
+}
+
+impl MapFormat {
+    /// ファイルパスの拡張子から出力形式を判定
+    pub fn from_path(path: &std::path::Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("kml") => MapFormat::Kml,
+            _ => MapFormat::GeoJson,
+        }
+    }
+}
+
+/// 地図出力設定
+#[derive(Debug, Clone)]
+pub struct MapConfig {
+    /// 出力ファイルパス
+    pub path: PathBuf,
+    /// ローカル座標原点の測地座標
+    pub origin: GeodeticPosition,
+}
+
+/// 地図上の図形
+#[derive(Debug, Clone, PartialEq)]
+enum Geometry {
+    Point(Position3D),
+    Polygon(Vec<Position3D>),
+    LineString(Vec<Position3D>),
+}
+
+/// 地図に出力する地物（ローカル座標）
+#[derive(Debug, Clone)]
+struct MapFeature {
+    name: String,
+    /// 種別（command_post, defended_asset, sensor, launcher, sensor_coverage, arrival_area, target_track, missile_track）
+    kind: &'static str,
+    /// 陣営（friendly / enemy）
+    side: &'static str,
+    geometry: Geometry,
+    /// 付加属性
+    properties: Vec<(&'static str, Value)>,
+}
+
+/// 中心・半径から円ポリゴン（閉じたリング、反時計回り）を作成
+fn circle(center: &Position3D, radius: f64) -> Vec<Position3D> {
+    (0..=CIRCLE_SEGMENTS)
+        .map(|i| {
+            let angle = 2.0 * std::f64::consts::PI * (i % CIRCLE_SEGMENTS) as f64 / CIRCLE_SEGMENTS as f64;
+            Position3D::new(center.x + radius * angle.cos(), center.y + radius * angle.sin(), center.z)
+        })
+        .collect()
+}
+
+/// 扇形（中心から方位角 `boresight_deg` を中心に全幅 `width_deg` の円弧を経て中心に戻る）の頂点列
+fn sector(center: &Position3D, radius: f64, boresight_deg: f64, width_deg: f64) -> Vec<Position3D> {
+    let segments = ((CIRCLE_SEGMENTS as f64 * width_deg / 360.0).ceil() as usize).max(1);
+    let start_deg = boresight_deg - width_deg / 2.0;
+    let arc = (0..=segments).map(|i| {
+        let angle = (start_deg + width_deg * i as f64 / segments as f64).to_radians();
+        Position3D::new(center.x + radius * angle.cos(), center.y + radius * angle.sin(), center.z)
+    });
+    std::iter::once(*center).chain(arc).chain(std::iter::once(*center)).collect()
+}
+
+/// シナリオ設定から配置・覆域の地物を作成
+fn deployment_features(scenario: &ScenarioConfig) -> Vec<MapFeature> {
+    let mut features = Vec::new();
+
+    let cp = &scenario.command_post;
+    let cp_position = Position3D::new(cp.position.x_m, cp.position.y_m, 0.0);
+    features.push(MapFeature {
+        name: "CP001".to_string(),
+        kind: "command_post",
+        side: "friendly",
+        geometry: Geometry::Point(cp_position),
+        properties: vec![("arrival_radius_m", json!(cp.arrival_radius_m))],
+    });
+    features.push(MapFeature {
+        name: "CP001 到達判定範囲".to_string(),
+        kind: "arrival_area",
+        side: "friendly",
+        geometry: Geometry::Polygon(circle(&cp_position, cp.arrival_radius_m)),
+        properties: vec![("radius_m", json!(cp.arrival_radius_m))],
+    });
+
+    for asset in &scenario.defended_assets {
+        let position = Position3D::new(asset.position.x_m, asset.position.y_m, 0.0);
+        features.push(MapFeature {
+            name: asset.id.clone(),
+            kind: "defended_asset",
+            side: "friendly",
+            geometry: Geometry::Point(position),
+            properties: vec![
+                ("asset_type", json!(asset.r#type)),
+                ("arrival_radius_m", json!(asset.arrival_radius_m)),
+                ("value", json!(asset.value)),
+            ],
+        });
+        features.push(MapFeature {
+            name: format!("{} 到達判定範囲", asset.id),
+            kind: "arrival_area",
+            side: "friendly",
+            geometry: Geometry::Polygon(circle(&position, asset.arrival_radius_m)),
+            properties: vec![("radius_m", json!(asset.arrival_radius_m))],
+        });
+    }
+
+    for sensor in &scenario.friendly_forces.sensors {
+        let position = Position3D::new(sensor.pos.x_m, sensor.pos.y_m, sensor.pos.z_m);
+        features.push(MapFeature {
+            name: sensor.id.clone(),
+            kind: "sensor",
+            side: "friendly",
+            geometry: Geometry::Point(position),
+            properties: vec![("detection_range_m", json!(sensor.range_m))],
+        });
+        features.push(MapFeature {
+            name: format!("{} 探知範囲", sensor.id),
+            kind: "sensor_coverage",
+            side: "friendly",
+            geometry: Geometry::Polygon(match &sensor.field_of_view {
+                Some(fov) if fov.azimuth_width_deg < 360.0 => {
+                    sector(&position, sensor.range_m, fov.boresight_az_deg, fov.azimuth_width_deg)
+                }
+                _ => circle(&position, sensor.range_m),
+            }),
+            properties: vec![("radius_m", json!(sensor.range_m))],
+        });
+    }
+
+    for launcher in &scenario.friendly_forces.launchers {
+        features.push(MapFeature {
+            name: launcher.id.clone(),
+            kind: "launcher",
+            side: "friendly",
+            geometry: Geometry::Point(Position3D::new(launcher.pos.x_m, launcher.pos.y_m, launcher.pos.z_m)),
+            properties: vec![
+                ("missiles_loaded", json!(launcher.missiles_loaded)),
+                ("cooldown_s", json!(launcher.cooldown_s)),
+            ],
+        });
+    }
+
+    features
+}
+
+/// 地物をGeoJSON文字列に変換
+fn to_geojson(features: &[MapFeature], reference: &GeodeticReference) -> String {
+    let coordinate = |position: &Position3D| -> Value {
+        let geodetic = reference.local_to_geodetic(position);
+        json!([geodetic.longitude_deg, geodetic.latitude_deg, geodetic.altitude_m])
+    };
+
+    let features: Vec<Value> = features
+        .iter()
+        .map(|feature| {
+            let geometry = match &feature.geometry {
+                Geometry::Point(p) => json!({ "type": "Point", "coordinates": coordinate(p) }),
+                Geometry::Polygon(ring) => json!({
+                    "type": "Polygon",
+                    "coordinates": [ring.iter().map(coordinate).collect::<Vec<_>>()],
+                }),
+                Geometry::LineString(points) => json!({
+                    "type": "LineString",
+                    "coordinates": points.iter().map(coordinate).collect::<Vec<_>>(),
+                }),
+            };
+            let mut properties = serde_json::Map::new();
+            properties.insert("name".to_string(), json!(feature.name));
+            properties.insert("kind".to_string(), json!(feature.kind));
+            properties.insert("side".to_string(), json!(feature.side));
+            for (key, value) in &feature.properties {
+                properties.insert(key.to_string(), value.clone());
+            }
+            json!({ "type": "Feature", "geometry": geometry, "properties": properties })
+        })
+        .collect();
+
+    let collection = json!({ "type": "FeatureCollection", "features": features });
+    serde_json::to_string_pretty(&collection).unwrap_or_default()
+}
+
+/// XML特殊文字のエスケープ
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+/// 地物をKML文字列に変換
+fn to_kml(name: &str, features: &[MapFeature], reference: &GeodeticReference) -> String {
+    let coordinates = |points: &[Position3D]| -> String {
+        points
+            .iter()
+            .map(|p| {
+                let g = reference.local_to_geodetic(p);
+                format!("{:.7},{:.7},{:.1}", g.longitude_deg, g.latitude_deg, g.altitude_m)
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+
+    let mut kml = String::new();
+    kml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    kml.push_str("<kml xmlns=\"http://www.opengis.net/kml/2.2\">\n<Document>\n");
+    kml.push_str(&format!("<name>{}</name>\n", escape_xml(name)));
+    // スタイル: 友軍=青、敵軍=赤、覆域=半透明
+    kml.push_str("<Style id=\"friendly\"><IconStyle><color>ffff0000</color></IconStyle><LineStyle><color>ffff0000</color><width>2</width></LineStyle><PolyStyle><color>33ff0000</color></PolyStyle></Style>\n");
+    kml.push_str("<Style id=\"enemy\"><IconStyle><color>ff0000ff</color></IconStyle><LineStyle><color>ff0000ff</color><width>2</width></LineStyle><PolyStyle><color>330000ff</color></PolyStyle></Style>\n");
+
+    for feature in features {
+        kml.push_str("<Placemark>\n");
+        kml.push_str(&format!("<name>{}</name>\n", escape_xml(&feature.name)));
+        kml.push_str(&format!("<styleUrl>#{}</styleUrl>\n", feature.side));
+        kml.push_str("<ExtendedData>");
+        kml.push_str(&format!("<Data name=\"kind\"><value>{}</value></Data>", feature.kind));
+        for (key, value) in &feature.properties {
+            let text = value.as_str().map(str::to_string).unwrap_or_else(|| value.to_string());
+            kml.push_str(&format!("<Data name=\"{}\"><value>{}</value></Data>", key, escape_xml(&text)));
+        }
+        kml.push_str("</ExtendedData>\n");
+        match &feature.geometry {
+            Geometry::Point(p) => {
+                kml.push_str(&format!(
+                    "<Point><altitudeMode>absolute</altitudeMode><coordinates>{}</coordinates></Point>\n",
+                    coordinates(std::slice::from_ref(p))
+                ));
+            }
+            Geometry::Polygon(ring) => {
+                kml.push_str(&format!(
+                    "<Polygon><tessellate>1</tessellate><outerBoundaryIs><LinearRing><coordinates>{}</coordinates></LinearRing></outerBoundaryIs></Polygon>\n",
+                    coordinates(ring)
+                ));
+            }
+            Geometry::LineString(points) => {
+                kml.push_str(&format!(
+                    "<LineString><altitudeMode>absolute</altitudeMode><coordinates>{}</coordinates></LineString>\n",
+                    coordinates(points)
+                ));
+            }
+        }
+        kml.push_str("</Placemark>\n");
+    }
+
+    kml.push_str("</Document>\n</kml>\n");
+    kml
+}
+
+/// 地物をファイルに出力
+fn write_features(config: &MapConfig, name: &str, features: &[MapFeature]) -> std::io::Result<()> {
+    let reference = GeodeticReference::new(config.origin);
+    let content = match MapFormat::from_path(&config.path) {
+        MapFormat::GeoJson => to_geojson(features, &reference),
+        MapFormat::Kml => to_kml(name, features, &reference),
+    };
+    fs::write(&config.path, content)?;
+    info!("地図出力完了: {} （地物数: {}）", config.path.display(), features.len());
+    Ok(())
+}
+
+/// 実行前のシナリオ配置・覆域を地図形式で出力
+///
+/// # 引数
+///
+/// * `config` - 地図出力設定
+/// * `scenario` - シナリオ設定
+pub fn write_scenario_map(config: &MapConfig, scenario: &ScenarioConfig) -> std::io::Result<()> {
+    write_features(config, &scenario.meta.name, &deployment_features(scenario))
+}
+
+/// 実行後の配置・覆域・航跡を地図形式で出力するエクスポーター
+pub struct MapExporter {
+    config: MapConfig,
+    recorder: TrackRecorder,
+}
+
+impl MapExporter {
+    /// 新しい地図エクスポーターを作成
+    ///
+    /// # 引数
+    ///
+    /// * `config` - 地図出力設定
+    pub fn new(config: MapConfig) -> Self {
+        Self { config, recorder: TrackRecorder::new(TRACK_SAMPLE_INTERVAL_S) }
+    }
+}
+
+impl ISimulationObserver for MapExporter {
+    fn on_step(&mut self, engine: &SimulationEngine) -> std::io::Result<()> {
+        self.recorder.record(engine);
+        Ok(())
+    }
+
+    fn on_finish(&mut self, engine: &SimulationEngine) -> std::io::Result<()> {
+        let mut features = deployment_features(&engine.scenario_config);
+        features.extend(
+            self.recorder
+                .tracks()
+                .iter()
+                .filter(|track| track.points.len() >= 2)
+                .map(|track| {
+                    let (kind, side) = match track.kind {
+                        TrackKind::Target => ("target_track", "enemy"),
+                        TrackKind::Missile => ("missile_track", "friendly"),
+                    };
+                    MapFeature {
+                        name: track.id.clone(),
+                        kind,
+                        side,
+                        geometry: Geometry::LineString(track.points.iter().map(|p| p.position).collect()),
+                        properties: vec![("outcome", json!(track.outcome.as_str()))],
+                    }
+                }),
+        );
+        write_features(&self.config, &engine.scenario_config.meta.name, &features)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_format_from_path() {
+        assert_eq!(MapFormat::from_path(std::path::Path::new("out.kml")), MapFormat::Kml);
+        assert_eq!(MapFormat::from_path(std::path::Path::new("out.geojson")), MapFormat::GeoJson);
+    }
+
+    #[test]
+    fn test_geojson_circle_is_closed_ring() {
+        let reference = GeodeticReference::new(GeodeticPosition::new(35.0, 135.0, 0.0));
+        let feature = MapFeature {
+            name: "S001".to_string(),
+            kind: "sensor_coverage",
+            side: "friendly",
+            geometry: Geometry::Polygon(circle(&Position3D::new(0.0, 0.0, 0.0), 10_000.0)),
+            properties: vec![],
+        };
+        let parsed: Value = serde_json::from_str(&to_geojson(&[feature], &reference)).unwrap();
+        let ring = parsed["features"][0]["geometry"]["coordinates"][0].as_array().unwrap();
+        assert_eq!(ring.len(), CIRCLE_SEGMENTS + 1);
+        assert_eq!(ring.first(), ring.last());
+    }
+}
@@ -0,0 +1,53 @@
+//! # Export モジュール
+//!
+//! シミュレーション結果を外部ツール向けの形式で出力するエクスポーターを提供します。
+//!
+//! エクスポーターは`ISimulationObserver`トレイトを実装し、シミュレーション
+//! エンジンに登録されることで、開始・各ステップ・終了の各時点で
+//! エンジンの状態と当該ステップのイベントを受け取ります。
+//!
+//! ## 提供するエクスポーター
+//!
+//! - **dis**: IEEE 1278.1 DIS PDU（Entity State / Fire / Detonation）のUDP送信
+//! - **acmi**: Tacview互換のテキスト形式ACMIリプレイファイル
+//! - **map**: 配置・覆域・航跡のGeoJSON/KMLファイル（実行前の`--info`でも出力可能）
+//! - **plot**: XY平面図・ミサイル高度-時間図のSVGファイル（`defsim plot`）
+
+use crate::simulation::SimulationEngine;
+
+pub mod acmi;
+pub mod dis;
+pub mod map;
+pub mod plot;
+pub mod track;
+
+/// シミュレーション進行を監視するオブザーバーのインターフェース
+///
+/// シミュレーションエンジンに登録され、エンジンの進行に合わせて呼び出されます。
+/// 当該ステップで発生したイベントは`SimulationEngine::events`から参照できます。
+pub trait ISimulationObserver {
+    /// シミュレーション開始時の処理
+    ///
+    /// # 引数
+    ///
+    /// * `engine` - 初期化済みのシミュレーションエンジン
+    fn on_start(&mut self, _engine: &SimulationEngine) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    /// 1ステップ完了後の処理
+    ///
+    /// # 引数
+    ///
+    /// * `engine` - ステップ処理後のシミュレーションエンジン
+    fn on_step(&mut self, engine: &SimulationEngine) -> std::io::Result<()>;
+
+    /// シミュレーション終了時の処理
+    ///
+    /// # 引数
+    ///
+    /// * `engine` - 終了時点のシミュレーションエンジン
+    fn on_finish(&mut self, _engine: &SimulationEngine) -> std::io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,363 @@
+//! # SVG プロット
+//!
+//! 交戦全体を素早く確認するための静的SVG図を出力します。
+//!
+//! - **XY平面図**: `region_rect`、友軍配置範囲（`deploy_rect_xy`）、敵出現範囲（`spawn_rect_xy`）、
+//!   センサー探知範囲、ランチャー、指揮所到達判定範囲、ターゲット・ミサイル航跡（結果別に色分け）
+//! - **高度-時間図**: 各ミサイルの高度プロファイル
+
+use std::fmt::Write as _;
+use std::fs;
+use std::path::PathBuf;
+use tracing::info;
+
+use crate::export::ISimulationObserver;
+use crate::export::track::{Track, TrackKind, TrackRecorder};
+use crate::scenario::{RegionRect, ScenarioConfig};
+use crate::simulation::SimulationEngine;
+
+/// 図の描画領域の幅（ピクセル）
+const PLOT_WIDTH: f64 = 900.0;
+/// 図の余白（ピクセル）
+const MARGIN: f64 = 60.0;
+/// 凡例領域の幅（ピクセル）
+const LEGEND_WIDTH: f64 = 180.0;
+/// 航跡の記録間隔（秒）
+const TRACK_SAMPLE_INTERVAL_S: f64 = 1.0;
+
+/// プロット出力設定
+#[derive(Debug, Clone)]
+pub struct PlotConfig {
+    /// XY平面図の出力パス
+    pub xy_path: PathBuf,
+    /// 高度-時間図の出力パス
+    pub altitude_path: PathBuf,
+}
+
+impl PlotConfig {
+    /// XY平面図のパスから設定を作成（高度図は`<名前>_altitude.svg`）
+    ///
+    /// # 引数
+    ///
+    /// * `xy_path` - XY平面図の出力パス
+    pub fn from_xy_path(xy_path: PathBuf) -> Self {
+        let stem = xy_path.file_stem().and_then(|s| s.to_str()).unwrap_or("plot");
+        let altitude_path = xy_path.with_file_name(format!("{}_altitude.svg", stem));
+        Self { xy_path, altitude_path }
+    }
+}
+
+/// 航跡の結果（`TrackOutcome::as_str`）に対応する描画色
+fn outcome_color(outcome: &str) -> &'static str {
+    match outcome {
+        "breakthrough" => "#d62728",
+        "destroyed" => "#7f7f7f",
+        "left_area" => "#ff7f0e",
+        "hit" => "#1f77b4",
+        "self_destruct" | "target_lost" => "#9467bd",
+        "out_of_bounds" => "#8c564b",
+        _ => "#17becf",
+    }
+}
+
+/// 凡例項目（結果, 表示名）
+const LEGEND_ITEMS: [(&str, &str); 7] = [
+    ("breakthrough", "ターゲット: 突破"),
+    ("destroyed", "ターゲット: 撃破"),
+    ("left_area", "ターゲット: 領域外"),
+    ("hit", "ミサイル: 命中"),
+    ("self_destruct", "ミサイル: 自爆/目標消失"),
+    ("out_of_bounds", "ミサイル: 領域外"),
+    ("active", "飛行中"),
+];
+
+/// ワールド座標から画素座標への線形変換
+struct Axis {
+    min: f64,
+    max: f64,
+    pixel_start: f64,
+    pixel_end: f64,
+}
+
+impl Axis {
+    fn map(&self, value: f64) -> f64 {
+        self.pixel_start + (value - self.min) / (self.max - self.min) * (self.pixel_end - self.pixel_start)
+    }
+
+    fn scale(&self) -> f64 {
+        ((self.pixel_end - self.pixel_start) / (self.max - self.min)).abs()
+    }
+
+    /// 目盛り値（おおよそ`count`個、1・2・5系列）
+    fn ticks(&self, count: usize) -> Vec<f64> {
+        let raw_step = (self.max - self.min) / count as f64;
+        let magnitude = 10f64.powf(raw_step.log10().floor());
+        let step = [1.0, 2.0, 5.0, 10.0]
+            .iter()
+            .map(|m| m * magnitude)
+            .find(|s| *s >= raw_step)
+            .unwrap_or(raw_step);
+        let first = (self.min / step).ceil() as i64;
+        let last = (self.max / step).floor() as i64;
+        (first..=last).map(|i| i as f64 * step).collect()
+    }
+}
+
+/// SVG文書の組み立て
+struct Svg {
+    body: String,
+    width: f64,
+    height: f64,
+}
+
+impl Svg {
+    fn new(width: f64, height: f64) -> Self {
+        Self { body: String::new(), width, height }
+    }
+
+    fn push(&mut self, element: String) {
+        self.body.push_str(&element);
+        self.body.push('\n');
+    }
+
+    fn text(&mut self, x: f64, y: f64, anchor: &str, content: &str) {
+        self.push(format!(
+            r#"<text x="{:.1}" y="{:.1}" text-anchor="{}" font-size="12" font-family="sans-serif">{}</text>"#,
+            x, y, anchor, content
+        ));
+    }
+
+    fn polyline(&mut self, points: &[(f64, f64)], color: &str, width: f64) {
+        let mut path = String::new();
+        for (x, y) in points {
+            let _ = write!(path, "{:.1},{:.1} ", x, y);
+        }
+        self.push(format!(
+            r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="{}" stroke-opacity="0.85"/>"#,
+            path.trim_end(), color, width
+        ));
+    }
+
+    /// 軸・目盛り・ラベルの描画
+    fn axes(&mut self, x: &Axis, y: &Axis, x_label: &str, y_label: &str, unit_divisor: (f64, f64)) {
+        let (left, right) = (x.pixel_start, x.pixel_end);
+        let (bottom, top) = (y.pixel_start, y.pixel_end);
+        self.push(format!(
+            r##"<rect x="{:.1}" y="{:.1}" width="{:.1}" height="{:.1}" fill="none" stroke="#333"/>"##,
+            left, top, right - left, bottom - top
+        ));
+        for tick in x.ticks(8) {
+            let px = x.map(tick);
+            self.push(format!(r##"<line x1="{px:.1}" y1="{bottom:.1}" x2="{px:.1}" y2="{:.1}" stroke="#333"/>"##, bottom + 5.0));
+            self.text(px, bottom + 18.0, "middle", &format!("{}", tick / unit_divisor.0));
+        }
+        for tick in y.ticks(8) {
+            let py = y.map(tick);
+            self.push(format!(r##"<line x1="{:.1}" y1="{py:.1}" x2="{left:.1}" y2="{py:.1}" stroke="#333"/>"##, left - 5.0));
+            self.text(left - 8.0, py + 4.0, "end", &format!("{}", tick / unit_divisor.1));
+        }
+        self.text((left + right) / 2.0, bottom + 40.0, "middle", x_label);
+        self.push(format!(
+            r#"<text x="{:.1}" y="{:.1}" text-anchor="middle" font-size="12" font-family="sans-serif" transform="rotate(-90 {:.1} {:.1})">{}</text>"#,
+            left - 45.0, (top + bottom) / 2.0, left - 45.0, (top + bottom) / 2.0, y_label
+        ));
+    }
+
+    fn legend(&mut self, x: f64, y: f64, items: &[(&str, &str)]) {
+        for (i, (color, label)) in items.iter().enumerate() {
+            let row = y + i as f64 * 18.0;
+            self.push(format!(
+                r#"<line x1="{:.1}" y1="{:.1}" x2="{:.1}" y2="{:.1}" stroke="{}" stroke-width="3"/>"#,
+                x, row, x + 20.0, row, color
+            ));
+            self.text(x + 26.0, row + 4.0, "start", label);
+        }
+    }
+
+    fn finish(self) -> String {
+        format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w:.0}\" height=\"{h:.0}\" viewBox=\"0 0 {w:.0} {h:.0}\">\n\
+             <rect width=\"100%\" height=\"100%\" fill=\"white\"/>\n{}</svg>\n",
+            self.body,
+            w = self.width,
+            h = self.height
+        )
+    }
+}
+
+/// XY平面図を描画
+fn render_xy(scenario: &ScenarioConfig, tracks: &[Track]) -> String {
+    let region = &scenario.world.region_rect;
+    let plot_height = PLOT_WIDTH * (region.ymax_m - region.ymin_m) / (region.xmax_m - region.xmin_m);
+    let mut svg = Svg::new(PLOT_WIDTH + 2.0 * MARGIN + LEGEND_WIDTH, plot_height + 2.0 * MARGIN);
+    let x = Axis { min: region.xmin_m, max: region.xmax_m, pixel_start: MARGIN, pixel_end: MARGIN + PLOT_WIDTH };
+    let y = Axis { min: region.ymin_m, max: region.ymax_m, pixel_start: MARGIN + plot_height, pixel_end: MARGIN };
+
+    svg.text(MARGIN, MARGIN - 20.0, "start", &format!("{} — XY平面", scenario.meta.name));
+
+    let rect = |svg: &mut Svg, r: &RegionRect, color: &str| {
+        svg.push(format!(
+            r#"<rect x="{:.1}" y="{:.1}" width="{:.1}" height="{:.1}" fill="{}" fill-opacity="0.05" stroke="{}" stroke-dasharray="6,4"/>"#,
+            x.map(r.xmin_m), y.map(r.ymax_m),
+            x.map(r.xmax_m) - x.map(r.xmin_m), y.map(r.ymin_m) - y.map(r.ymax_m),
+            color, color
+        ));
+    };
+    if let Some(deploy) = &scenario.friendly_forces.deploy_rect_xy {
+        rect(&mut svg, deploy, "#1f77b4");
+    }
+    rect(&mut svg, &scenario.enemy_forces.spawn_rect_xy, "#d62728");
+
+    // センサー探知範囲
+    for sensor in &scenario.friendly_forces.sensors {
+        svg.push(format!(
+            r##"<circle cx="{:.1}" cy="{:.1}" r="{:.1}" fill="#2ca02c" fill-opacity="0.08" stroke="#2ca02c"/>"##,
+            x.map(sensor.pos.x_m), y.map(sensor.pos.y_m), sensor.range_m * x.scale()
+        ));
+        svg.push(format!(
+            r##"<circle cx="{:.1}" cy="{:.1}" r="3" fill="#2ca02c"/>"##,
+            x.map(sensor.pos.x_m), y.map(sensor.pos.y_m)
+        ));
+    }
+
+    // 指揮所と到達判定範囲
+    let cp = &scenario.command_post;
+    let (cp_x, cp_y) = (x.map(cp.position.x_m), y.map(cp.position.y_m));
+    svg.push(format!(
+        r##"<circle cx="{:.1}" cy="{:.1}" r="{:.1}" fill="none" stroke="#000" stroke-dasharray="4,3"/>"##,
+        cp_x, cp_y, cp.arrival_radius_m * x.scale()
+    ));
+    svg.push(format!(r##"<rect x="{:.1}" y="{:.1}" width="8" height="8" fill="#000"/>"##, cp_x - 4.0, cp_y - 4.0));
+    svg.text(cp_x + 8.0, cp_y - 8.0, "start", "CP");
+
+    // 防護対象と到達判定範囲
+    for asset in &scenario.defended_assets {
+        let (ax, ay) = (x.map(asset.position.x_m), y.map(asset.position.y_m));
+        svg.push(format!(
+            r##"<circle cx="{:.1}" cy="{:.1}" r="{:.1}" fill="none" stroke="#000" stroke-dasharray="4,3"/>"##,
+            ax, ay, asset.arrival_radius_m * x.scale()
+        ));
+        svg.push(format!(r##"<rect x="{:.1}" y="{:.1}" width="8" height="8" fill="none" stroke="#000" stroke-width="2"/>"##, ax - 4.0, ay - 4.0));
+        svg.text(ax + 8.0, ay - 8.0, "start", &asset.id);
+    }
+
+    // 航跡（ターゲットを先に描画し、ミサイルを上に重ねる）
+    for kind in [TrackKind::Target, TrackKind::Missile] {
+        for track in tracks.iter().filter(|t| t.kind == kind && t.points.len() >= 2) {
+            let points: Vec<(f64, f64)> = track.points.iter().map(|p| (x.map(p.position.x), y.map(p.position.y))).collect();
+            let width = if kind == TrackKind::Target { 1.5 } else { 1.0 };
+            svg.polyline(&points, outcome_color(track.outcome.as_str()), width);
+        }
+    }
+
+    // ランチャー
+    for launcher in &scenario.friendly_forces.launchers {
+        let (lx, ly) = (x.map(launcher.pos.x_m), y.map(launcher.pos.y_m));
+        svg.push(format!(
+            r##"<polygon points="{:.1},{:.1} {:.1},{:.1} {:.1},{:.1}" fill="#1f77b4" stroke="#000"/>"##,
+            lx, ly - 6.0, lx - 5.0, ly + 4.0, lx + 5.0, ly + 4.0
+        ));
+    }
+
+    svg.axes(&x, &y, "X [km]", "Y [km]", (1000.0, 1000.0));
+
+    let mut legend: Vec<(&str, &str)> = LEGEND_ITEMS
+        .iter()
+        .map(|(outcome, label)| (outcome_color(outcome), *label))
+        .collect();
+    legend.extend([("#2ca02c", "センサー探知範囲"), ("#000", "指揮所到達判定範囲"), ("#d62728", "敵出現範囲")]);
+    if scenario.friendly_forces.deploy_rect_xy.is_some() {
+        legend.push(("#1f77b4", "友軍配置範囲"));
+    }
+    svg.legend(MARGIN + PLOT_WIDTH + 20.0, MARGIN + 10.0, &legend);
+
+    svg.finish()
+}
+
+/// 高度-時間図を描画
+fn render_altitude(scenario: &ScenarioConfig, tracks: &[Track]) -> String {
+    let plot_height = PLOT_WIDTH * 0.5;
+    let mut svg = Svg::new(PLOT_WIDTH + 2.0 * MARGIN + LEGEND_WIDTH, plot_height + 2.0 * MARGIN);
+    let missiles: Vec<&Track> = tracks.iter().filter(|t| t.kind == TrackKind::Missile && t.points.len() >= 2).collect();
+
+    let t_max = missiles
+        .iter()
+        .filter_map(|t| t.points.last().map(|p| p.time_s))
+        .fold(0.0, f64::max)
+        .max(1.0);
+    let z_max = scenario.world.z_limits_m[1];
+    let x = Axis { min: 0.0, max: t_max, pixel_start: MARGIN, pixel_end: MARGIN + PLOT_WIDTH };
+    let y = Axis { min: scenario.world.z_limits_m[0], max: z_max, pixel_start: MARGIN + plot_height, pixel_end: MARGIN };
+
+    svg.text(MARGIN, MARGIN - 20.0, "start", &format!("{} — ミサイル高度プロファイル", scenario.meta.name));
+    for track in &missiles {
+        let points: Vec<(f64, f64)> = track.points.iter().map(|p| (x.map(p.time_s), y.map(p.position.z))).collect();
+        svg.polyline(&points, outcome_color(track.outcome.as_str()), 1.0);
+    }
+    svg.axes(&x, &y, "時刻 [s]", "高度 [m]", (1.0, 1.0));
+
+    let legend: Vec<(&str, &str)> = LEGEND_ITEMS[3..]
+        .iter()
+        .map(|(outcome, label)| (outcome_color(outcome), *label))
+        .collect();
+    svg.legend(MARGIN + PLOT_WIDTH + 20.0, MARGIN + 10.0, &legend);
+
+    svg.finish()
+}
+
+/// 実行結果をSVG図として出力するエクスポーター
+pub struct PlotExporter {
+    config: PlotConfig,
+    recorder: TrackRecorder,
+}
+
+impl PlotExporter {
+    /// 新しいプロットエクスポーターを作成
+    ///
+    /// # 引数
+    ///
+    /// * `config` - プロット出力設定
+    pub fn new(config: PlotConfig) -> Self {
+        Self { config, recorder: TrackRecorder::new(TRACK_SAMPLE_INTERVAL_S) }
+    }
+}
+
+impl ISimulationObserver for PlotExporter {
+    fn on_step(&mut self, engine: &SimulationEngine) -> std::io::Result<()> {
+        self.recorder.record(engine);
+        Ok(())
+    }
+
+    fn on_finish(&mut self, engine: &SimulationEngine) -> std::io::Result<()> {
+        let tracks = self.recorder.tracks();
+        fs::write(&self.config.xy_path, render_xy(&engine.scenario_config, tracks))?;
+        fs::write(&self.config.altitude_path, render_altitude(&engine.scenario_config, tracks))?;
+        info!(
+            "プロット出力完了: {}, {}",
+            self.config.xy_path.display(),
+            self.config.altitude_path.display()
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_axis_ticks() {
+        let axis = Axis { min: -500_000.0, max: 500_000.0, pixel_start: 0.0, pixel_end: 100.0 };
+        let ticks = axis.ticks(8);
+        assert_eq!(ticks.first(), Some(&-400_000.0));
+        assert!(ticks.contains(&0.0));
+        assert_eq!(axis.map(0.0), 50.0);
+    }
+
+    #[test]
+    fn test_altitude_path_from_xy_path() {
+        let config = PlotConfig::from_xy_path(PathBuf::from("out/plane.svg"));
+        assert_eq!(config.altitude_path, PathBuf::from("out/plane_altitude.svg"));
+    }
+}
@@ -0,0 +1,153 @@
+//! # 航跡記録
+//!
+//! エクスポーター間で共有する、ターゲット・ミサイル航跡の記録機能を提供します。
+//! 各ステップのエンジン状態から一定間隔で位置を記録し、終了時には
+//! 最終位置と結果（`AgentStatus` / `MissileEndReason`）を確定します。
+
+use std::collections::HashMap;
+
+use crate::models::{AgentStatus, IAgent, MissileEndReason, Position3D};
+use crate::simulation::{SimulationEngine, SimulationEvent};
+
+/// 航跡の対象種別
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TrackKind {
+    Target,
+    Missile,
+}
+
+/// 航跡の結果
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TrackOutcome {
+    /// 終了していない（シミュレーション終了時点で飛行中）
+    Active,
+    /// ターゲットの終了状態
+    Target(AgentStatus),
+    /// ミサイルの終了理由
+    Missile(Option<MissileEndReason>),
+}
+
+impl TrackOutcome {
+    /// 結果を表す識別文字列
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TrackOutcome::Active => "active",
+            TrackOutcome::Target(AgentStatus::Destroyed) => "destroyed",
+            TrackOutcome::Target(AgentStatus::Reached) => "breakthrough",
+            TrackOutcome::Target(AgentStatus::Active) => "active",
+            TrackOutcome::Target(_) => "left_area",
+            TrackOutcome::Missile(Some(MissileEndReason::Hit)) => "hit",
+            TrackOutcome::Missile(Some(MissileEndReason::SelfDestruct)) => "self_destruct",
+            TrackOutcome::Missile(Some(MissileEndReason::TargetLost)) => "target_lost",
+            TrackOutcome::Missile(Some(MissileEndReason::OutOfBounds)) => "out_of_bounds",
+            TrackOutcome::Missile(None) => "active",
+        }
+    }
+}
+
+/// 航跡上の1点
+#[derive(Debug, Clone, Copy)]
+pub struct TrackPoint {
+    /// シミュレーション時刻（秒）
+    pub time_s: f64,
+    /// 位置（ローカル座標）
+    pub position: Position3D,
+}
+
+/// 1エージェント分の航跡
+#[derive(Debug, Clone)]
+pub struct Track {
+    pub id: String,
+    pub kind: TrackKind,
+    pub points: Vec<TrackPoint>,
+    pub outcome: TrackOutcome,
+    last_sample_time: f64,
+}
+
+impl Track {
+    fn new(id: &str, kind: TrackKind) -> Self {
+        Self {
+            id: id.to_string(),
+            kind,
+            points: Vec::new(),
+            outcome: TrackOutcome::Active,
+            last_sample_time: f64::NEG_INFINITY,
+        }
+    }
+}
+
+/// 航跡記録器
+pub struct TrackRecorder {
+    sample_interval_s: f64,
+    /// 出現順の航跡
+    tracks: Vec<Track>,
+    index: HashMap<String, usize>,
+}
+
+impl TrackRecorder {
+    /// 新しい航跡記録器を作成
+    ///
+    /// # 引数
+    ///
+    /// * `sample_interval_s` - 位置の記録間隔（秒）
+    pub fn new(sample_interval_s: f64) -> Self {
+        Self { sample_interval_s, tracks: Vec::new(), index: HashMap::new() }
+    }
+
+    /// 記録済みの航跡（出現順）
+    pub fn tracks(&self) -> &[Track] {
+        &self.tracks
+    }
+
+    fn track(&mut self, id: &str, kind: TrackKind) -> &mut Track {
+        let index = *self.index.entry(id.to_string()).or_insert_with(|| {
+            self.tracks.push(Track::new(id, kind));
+            self.tracks.len() - 1
+        });
+        &mut self.tracks[index]
+    }
+
+    /// 記録間隔に従って位置を追加（`force`指定時は常に追加）
+    fn sample(&mut self, id: &str, kind: TrackKind, time_s: f64, position: Position3D, force: bool) -> &mut Track {
+        let interval = self.sample_interval_s;
+        let track = self.track(id, kind);
+        if force || time_s - track.last_sample_time >= interval {
+            track.points.push(TrackPoint { time_s, position });
+            track.last_sample_time = time_s;
+        }
+        track
+    }
+
+    /// 1ステップ分のエンジン状態を記録
+    ///
+    /// # 引数
+    ///
+    /// * `engine` - ステップ処理後のシミュレーションエンジン
+    pub fn record(&mut self, engine: &SimulationEngine) {
+        let time_s = engine.current_time;
+
+        for target in engine.targets.iter().filter(|t| t.is_active()) {
+            self.sample(&target.id, TrackKind::Target, time_s, target.position, false);
+        }
+        for missile in engine.missiles.iter().filter(|m| m.is_active()) {
+            self.sample(&missile.id, TrackKind::Missile, time_s, missile.position, false);
+        }
+
+        // 終了時は最終位置と結果を記録
+        for event in &engine.events {
+            match event {
+                SimulationEvent::MissileEnded { missile } => {
+                    let track = self.sample(&missile.id, TrackKind::Missile, time_s, missile.position, true);
+                    track.outcome = TrackOutcome::Missile(missile.end_reason);
+                }
+                SimulationEvent::TargetEnded { target_id, status } => {
+                    if let Some(target) = engine.targets.iter().find(|t| &t.id == target_id) {
+                        let track = self.sample(&target.id, TrackKind::Target, time_s, target.position, true);
+                        track.outcome = TrackOutcome::Target(*status);
+                    }
+                }
+                SimulationEvent::MissileLaunched { .. } | SimulationEvent::AssetDisabled { .. } => {}
+            }
+        }
+    }
+}
@@ -2,11 +2,18 @@ mod models;
 mod scenario;
 mod simulation;
 mod logging;
+mod export;
+mod tui;
 
 use clap::{Arg, Command};
 use models::{Position3D as ModelPosition3D, *};
 use scenario::*;
 use simulation::SimulationEngine;
+use export::acmi::{AcmiConfig, AcmiExporter};
+use export::dis::{DisConfig, DisExporter};
+use export::map::{MapConfig, MapExporter, write_scenario_map};
+use export::plot::{PlotConfig, PlotExporter};
+use tui::TuiObserver;
 use logging::{LogConfig, LogOutput, init_logging, parse_log_level, ensure_log_directory};
 use tracing::{info, warn, error, debug, trace};
 
@@ -48,6 +55,7 @@ fn main() {
                 .long("verbose")
                 .action(clap::ArgAction::Count)
                 .help("詳細出力レベル (-v: 基本, -vv: 詳細, -vvv: デバッグ)")
+                .global(true)
         )
         .arg(
             Arg::new("log-level")
@@ -55,6 +63,7 @@ fn main() {
                 .value_name("LEVEL")
                 .help("ログレベルを指定 (trace, debug, info, warn, error)")
                 .default_value("info")
+                .global(true)
         )
         .arg(
             Arg::new("log-output")
@@ -62,6 +71,7 @@ fn main() {
                 .value_name("OUTPUT")
                 .help("ログ出力先を指定 (console, file, both)")
                 .default_value("both")
+                .global(true)
         )
         .arg(
             Arg::new("log-dir")
@@ -69,6 +79,79 @@ fn main() {
                 .value_name("DIR")
                 .help("ログファイルの出力ディレクトリ")
                 .default_value("logs")
+                .global(true)
+        )
+        .arg(
+            Arg::new("tui")
+                .long("tui")
+                .action(clap::ArgAction::SetTrue)
+                .help("ターミナルUIで戦域マップと状況をリアルタイム表示")
+                .long_help("戦域マップ、目標優先度（Tgo）、ランチャー状態、直近イベントを\n\
+                           ターミナルにリアルタイム表示します。\n\
+                           操作: [Space]一時停止 [+/-]速度変更 [q]終了\n\
+                           表示中はコンソールへのログ出力を停止し、ファイルにのみ出力します。")
+                .conflicts_with("info")
+        )
+        .arg(
+            Arg::new("origin")
+                .long("origin")
+                .value_name("LAT,LON[,ALT]")
+                .help("ローカル座標原点の測地座標（緯度,経度[,高度]）")
+                .long_help("シミュレーションのローカル座標（X=東, Y=北, Z=上）の原点となる\n\
+                           WGS84測地座標を指定します。DIS・ACMI・地図出力で使用されます。\n\
+                           省略時はシナリオの world.geodetic_reference を使用します。\n\
+                           基準点を持つシナリオでは、基準点と異なる原点は指定できません。")
+        )
+        .arg(
+            Arg::new("dis")
+                .long("dis")
+                .value_name("ADDR:PORT")
+                .help("DIS PDU（Entity State/Fire/Detonation）をUDPで送信")
+        )
+        .arg(
+            Arg::new("dis-exercise")
+                .long("dis-exercise")
+                .value_name("ID")
+                .help("DIS演習ID")
+                .default_value("1")
+                .value_parser(clap::value_parser!(u8))
+        )
+        .arg(
+            Arg::new("acmi")
+                .long("acmi")
+                .value_name("FILE")
+                .help("Tacview互換のACMIリプレイファイルを出力")
+        )
+        .arg(
+            Arg::new("map")
+                .long("map")
+                .value_name("FILE")
+                .help("配置・覆域・航跡をGeoJSON/KMLで出力（拡張子.kmlでKML）")
+                .long_help("指揮所・センサー・ランチャーの配置、探知範囲・到達判定範囲、\n\
+                           ターゲット・ミサイルの航跡を地図形式で出力します。\n\
+                           --info と併用した場合は実行前の配置・覆域のみを出力します。")
+        )
+        .subcommand(
+            Command::new("plot")
+                .about("交戦概要のSVG図（XY平面図・ミサイル高度-時間図）を出力")
+                .long_about("シナリオを実行し、XY平面図（領域・配置範囲・出現範囲・探知範囲・\n\
+                             ランチャー・指揮所到達判定範囲・航跡）と、ミサイルの\n\
+                             高度-時間図をSVGで出力します。")
+                .arg(
+                    Arg::new("scenario")
+                        .short('s')
+                        .long("scenario")
+                        .value_name("FILE")
+                        .help("シナリオファイル(.yaml)のパスを指定")
+                        .required(true)
+                )
+                .arg(
+                    Arg::new("output")
+                        .short('o')
+                        .long("output")
+                        .value_name("FILE")
+                        .help("XY平面図の出力パス（既定: <シナリオ名>.svg、高度図は<名前>_altitude.svg）")
+                )
         )
         .get_matches();
 
@@ -90,6 +173,8 @@ fn main() {
     };
 
     let log_output = match log_output_str.parse::<LogOutput>() {
+        // TUI表示中はコンソール出力が画面を崩すためファイルのみに出力
+        Ok(_) if matches.get_flag("tui") => LogOutput::File,
         Ok(output) => output,
         Err(e) => {
             eprintln!("エラー: {}", e);
@@ -133,9 +218,39 @@ fn main() {
         return;
     }
 
+    // プロットの生成
+    if let Some(plot_matches) = matches.subcommand_matches("plot") {
+        let scenario_path = plot_matches.get_one::<String>("scenario").unwrap();
+        let xy_path = plot_matches
+            .get_one::<String>("output")
+            .map(std::path::PathBuf::from)
+            .unwrap_or_else(|| {
+                let stem = std::path::Path::new(scenario_path).file_stem().unwrap_or_default();
+                std::path::PathBuf::from(stem).with_extension("svg")
+            });
+        let export_options = ExportOptions {
+            plot: Some(PlotConfig::from_xy_path(xy_path)),
+            ..Default::default()
+        };
+        if let Err(e) = run_scenario(scenario_path, false, verbose_level, &export_options) {
+            error!("エラー: {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    // 出力設定の解析
+    let export_options = match ExportOptions::from_matches(&matches) {
+        Ok(options) => options,
+        Err(e) => {
+            error!("エラー: {}", e);
+            std::process::exit(1);
+        }
+    };
+
     // シナリオファイルの処理
     if let Some(scenario_path) = matches.get_one::<String>("scenario") {
-        match run_scenario(scenario_path, matches.get_flag("info"), verbose_level) {
+        match run_scenario(scenario_path, matches.get_flag("info"), verbose_level, &export_options) {
             Ok(_) => {
                 if verbose_level > 0 {
                     info!("シナリオ実行が正常に完了しました。");
@@ -189,11 +304,23 @@ fn test_agent_models() {
         ring_spacing: 1500.0,
         start_angle: 0.0,
         ring_half_offset: true,
+        formation: Formation::Rings,
         endurance: 2,
         spawn_time: 120.0,
+        spawn_interval: 0.0,
+        spawn_jitter: None,
+        spawn_bounds: None,
         speed: 200.0,
         destination: command_post_pos,
         arrival_radius: 20000.0,
+        asset_id: "CP001".to_string(),
+        waypoints: Vec::new(),
+        maneuver: None,
+        ballistic: None,
+        cruise: None,
+        altitude_profile: None,
+        rcs_m2: 1.0,
+        decoy_ratio: 0.0,
     };
     
     let targets = target_group.generate_targets();
@@ -239,10 +366,15 @@ fn test_agent_models() {
                 launcher_selection: "XY".to_string(),
                 intercept: "3D".to_string(),
             },
+            geodetic_reference: None,
+            radar_horizon: false,
         },
         command_post: CommandPostConfig {
             position: Position2D { x_m: 800000.0, y_m: -800000.0 },
             arrival_radius_m: 20000.0,
+            value: 1.0,
+            decoy_discrimination: None,
+            track_process_noise_mps2: 10.0,
         },
         policy: PolicyConfig {
             tgo_definition: "XY".to_string(),
@@ -265,12 +397,14 @@ fn test_agent_models() {
                 max_accel_mps2: 50.0,
                 max_turn_rate_deg_s: 180.0,
                 intercept_radius_m: 10.0,
+                max_altitude_m: 5000.0,
             },
         },
         friendly_forces: FriendlyForcesConfig {
             deploy_rect_xy: None,
             sensors: vec![],
             launchers: vec![],
+            sensor_fusion: None,
         },
         enemy_forces: EnemyForcesConfig {
             spawn_rect_xy: RegionRect {
@@ -281,6 +415,7 @@ fn test_agent_models() {
             },
             groups: vec![],
         },
+        defended_assets: vec![],
         missile_defaults: MissileDefaults {
             kinematics: MissileKinematics {
                 initial_speed_mps: 100.0,
@@ -288,7 +423,10 @@ fn test_agent_models() {
                 max_accel_mps2: 50.0,
                 max_turn_rate_deg_s: 180.0,
                 intercept_radius_m: 10.0,
+                max_altitude_m: 5000.0,
             },
+            lethality: None,
+            types: vec![],
         },
     };
     
@@ -349,8 +487,98 @@ fn test_agent_models() {
     info!("=== ターゲットイベントログテスト完了 ===");
 }
 
+/// 外部出力の設定
+#[derive(Default)]
+struct ExportOptions {
+    /// ローカル座標原点（--origin 指定時。未指定時はシナリオの基準点を使用）
+    origin: Option<GeodeticPosition>,
+    /// DIS送信先
+    dis_address: Option<std::net::SocketAddr>,
+    /// DIS演習ID
+    dis_exercise_id: u8,
+    /// ACMI出力ファイル
+    acmi_path: Option<std::path::PathBuf>,
+    /// 地図出力ファイル
+    map_path: Option<std::path::PathBuf>,
+    /// SVGプロット出力設定
+    plot: Option<PlotConfig>,
+    /// ターミナルUI表示
+    tui: bool,
+}
+
+impl ExportOptions {
+    /// コマンドライン引数から出力設定を構築
+    fn from_matches(matches: &clap::ArgMatches) -> Result<Self, String> {
+        let origin = matches
+            .get_one::<String>("origin")
+            .map(|value| value.parse::<GeodeticPosition>())
+            .transpose()?;
+
+        let dis_address = matches
+            .get_one::<String>("dis")
+            .map(|address| {
+                std::net::ToSocketAddrs::to_socket_addrs(address.as_str())
+                    .map_err(|e| format!("無効なDIS送信先: {}: {}", address, e))?
+                    .next()
+                    .ok_or_else(|| format!("無効なDIS送信先: {}", address))
+            })
+            .transpose()?;
+
+        Ok(Self {
+            origin,
+            dis_address,
+            dis_exercise_id: *matches.get_one::<u8>("dis-exercise").unwrap(),
+            acmi_path: matches.get_one::<String>("acmi").map(Into::into),
+            map_path: matches.get_one::<String>("map").map(Into::into),
+            plot: None,
+            tui: matches.get_flag("tui"),
+        })
+    }
+
+    /// 測地座標を必要とする出力が指定されているか
+    fn needs_origin(&self) -> bool {
+        self.dis_address.is_some() || self.acmi_path.is_some() || self.map_path.is_some()
+    }
+
+    /// ローカル座標原点を決定（シナリオの基準点を優先し、なければ --origin）
+    ///
+    /// 測地座標で記述された位置は読み込み時に基準点でローカル座標へ変換されるため、
+    /// 基準点と異なる --origin を使うと全出力の位置がずれます。その場合はエラーとします。
+    fn origin_for(&self, scenario: &ScenarioConfig) -> Result<GeodeticPosition, String> {
+        match (scenario.geodetic_origin(), self.origin) {
+            (Some(reference), Some(origin)) if !same_origin(&reference, &origin) => Err(format!(
+                "--origin ({:.6}, {:.6}, {:.1}m) がシナリオの world.geodetic_reference ({:.6}, {:.6}, {:.1}m) と異なります",
+                origin.latitude_deg, origin.longitude_deg, origin.altitude_m,
+                reference.latitude_deg, reference.longitude_deg, reference.altitude_m,
+            )),
+            (Some(reference), _) => Ok(reference),
+            (None, Some(origin)) => Ok(origin),
+            (None, None) => Err(
+                "DIS・ACMI・地図出力には --origin またはシナリオの world.geodetic_reference が必要です".to_string()
+            ),
+        }
+    }
+
+    /// 地図出力設定を構築
+    fn map_config(&self, origin: GeodeticPosition) -> Option<MapConfig> {
+        self.map_path.clone().map(|path| MapConfig { path, origin })
+    }
+}
+
+/// 2つの原点が同じ位置か（緯度経度は約0.1mm、高度は1mmの許容差）
+fn same_origin(a: &GeodeticPosition, b: &GeodeticPosition) -> bool {
+    (a.latitude_deg - b.latitude_deg).abs() < 1e-9
+        && (a.longitude_deg - b.longitude_deg).abs() < 1e-9
+        && (a.altitude_m - b.altitude_m).abs() < 1e-3
+}
+
 /// シナリオファイルを読み込んで実行
-fn run_scenario(scenario_path: &str, info_only: bool, verbose_level: u8) -> Result<(), Box<dyn std::error::Error>> {
+fn run_scenario(
+    scenario_path: &str,
+    info_only: bool,
+    verbose_level: u8,
+    export_options: &ExportOptions,
+) -> Result<(), Box<dyn std::error::Error>> {
     // シナリオファイルの読み込み
     let scenario = ScenarioConfig::from_file(scenario_path)?;
     
@@ -361,17 +589,27 @@ fn run_scenario(scenario_path: &str, info_only: bool, verbose_level: u8) -> Resu
     // 情報表示のみの場合
     if info_only {
         scenario.print_summary();
+        if export_options.map_path.is_some() {
+            let origin = export_options.origin_for(&scenario)?;
+            if let Some(map_config) = export_options.map_config(origin) {
+                write_scenario_map(&map_config, &scenario)?;
+            }
+        }
         return Ok(());
     }
     
     // シナリオ実行
-    execute_scenario(scenario, verbose_level)?;
+    execute_scenario(scenario, verbose_level, export_options)?;
     
     Ok(())
 }
 
 /// シナリオの実行
-fn execute_scenario(scenario: ScenarioConfig, verbose_level: u8) -> Result<(), Box<dyn std::error::Error>> {
+fn execute_scenario(
+    scenario: ScenarioConfig,
+    verbose_level: u8,
+    export_options: &ExportOptions,
+) -> Result<(), Box<dyn std::error::Error>> {
     // 基本情報表示
     scenario.print_summary();
     
@@ -382,10 +620,42 @@ fn execute_scenario(scenario: ScenarioConfig, verbose_level: u8) -> Result<(), B
         debug!("  シード値: {}", scenario.sim.seed);
     }
     
+    // 測地座標を使う出力の原点（実行前に確定させる）
+    let origin = if export_options.needs_origin() {
+        Some(export_options.origin_for(&scenario)?)
+    } else {
+        None
+    };
+    
     // シミュレーションエンジンの作成と初期化
     let mut simulation = SimulationEngine::new(scenario, verbose_level);
     simulation.initialize()?;
     
+    // 外部出力の登録
+    if let Some(origin) = origin {
+        if let Some(address) = export_options.dis_address {
+            simulation.add_observer(Box::new(DisExporter::new(DisConfig {
+                address,
+                exercise_id: export_options.dis_exercise_id,
+                site_id: 1,
+                application_id: 1,
+                origin,
+            })?));
+        }
+        if let Some(path) = &export_options.acmi_path {
+            simulation.add_observer(Box::new(AcmiExporter::new(AcmiConfig { path: path.clone(), origin })?));
+        }
+        if let Some(map_config) = export_options.map_config(origin) {
+            simulation.add_observer(Box::new(MapExporter::new(map_config)));
+        }
+    }
+    if let Some(plot_config) = &export_options.plot {
+        simulation.add_observer(Box::new(PlotExporter::new(plot_config.clone())));
+    }
+    if export_options.tui {
+        simulation.add_observer(Box::new(TuiObserver::new()));
+    }
+    
     // シミュレーション実行
     simulation.run()?;
     
@@ -405,6 +675,13 @@ fn show_default_help() {
     info!("  --log-level <LEVEL>    ログレベル指定 (trace, debug, info, warn, error)");
     info!("  --log-output <OUTPUT>  ログ出力先指定 (console, file, both)");
     info!("  --log-dir <DIR>        ログファイル出力ディレクトリ");
+    info!("  --tui                  ターミナルUIでリアルタイム表示");
+    info!("  --origin <LAT,LON[,ALT]> ローカル座標原点の測地座標");
+    info!("  --dis <ADDR:PORT>      DIS PDUをUDPで送信 (--origin または基準点が必要)");
+    info!("  --dis-exercise <ID>    DIS演習ID");
+    info!("  --acmi <FILE>          ACMIリプレイファイルを出力 (--origin または基準点が必要)");
+    info!("  --map <FILE>           配置・覆域・航跡をGeoJSON/KMLで出力 (--origin または基準点が必要)");
+    info!("  plot -s <FILE> [-o <SVG>] XY平面図・ミサイル高度図をSVGで出力");
     info!("利用可能なシナリオファイル:");
     info!("  scenarios/scenario_simple_test.yaml     - 基本テスト用");
     info!("  scenarios/scenario_plane.yaml           - 標準シナリオ");
@@ -417,4 +694,9 @@ fn show_default_help() {
     info!("  defsim -s scenarios/scenario_multi_wave.yaml -i");
     info!("  defsim --test");
     info!("  defsim -s scenarios/scenario_plane.yaml --log-level debug --log-output file");
+    info!("  defsim -s scenarios/scenario_plane.yaml --origin 35.0,135.0 --dis 127.0.0.1:3000");
+    info!("  defsim -s scenarios/scenario_plane.yaml --origin 35.0,135.0 --acmi plane.acmi");
+    info!("  defsim -s scenarios/scenario_plane.yaml -i --origin 35.0,135.0 --map plane.kml");
+    info!("  defsim plot -s scenarios/scenario_plane.yaml -o plane.svg");
+    info!("  defsim -s scenarios/scenario_plane.yaml --tui");
 }
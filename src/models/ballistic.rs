@@ -0,0 +1,131 @@
+//! # Ballistic モジュール
+//!
+//! 弾道ミサイル型の敵ターゲットが従う重力弾道を定義します。
+//!
+//! 弾道は発射点・着弾点と、最高到達高度（アポジー）または飛翔時間から決まる
+//! 真空中の放物線軌道です。水平方向は等速、鉛直方向は重力加速度で減速・加速し、
+//! 通常のターゲットと異なり高度制限（0〜5000m）を超えて飛翔します。
+
+use crate::models::common::{Position3D, Velocity3D};
+use crate::models::maneuver::GRAVITY_MPS2;
+
+/// 弾道の形状指定
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BallisticShape {
+    /// 最高到達高度（メートル）
+    Apogee(f64),
+    /// 発射から着弾までの飛翔時間（秒）
+    FlightTime(f64),
+}
+
+/// 重力弾道
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BallisticTrajectory {
+    /// 発射点
+    pub launch: Position3D,
+    /// 着弾点
+    pub impact: Position3D,
+    /// 発射時の速度
+    pub initial_velocity: Velocity3D,
+    /// 発射から着弾までの飛翔時間（秒）
+    pub flight_time: f64,
+    /// 発射からの経過時間（秒）
+    pub elapsed: f64,
+}
+
+impl BallisticTrajectory {
+    /// 発射点・着弾点・弾道形状から弾道を計算
+    ///
+    /// # 引数
+    ///
+    /// * `launch` - 発射点
+    /// * `impact` - 着弾点
+    /// * `shape` - 最高到達高度または飛翔時間
+    ///
+    /// # 戻り値
+    ///
+    /// 計算された弾道、指定値で弾道が成立しない場合はNone
+    pub fn new(launch: Position3D, impact: Position3D, shape: BallisticShape) -> Option<Self> {
+        let g = GRAVITY_MPS2;
+        let drop = launch.z - impact.z;
+
+        // 鉛直初速度と飛翔時間: z(t) = z0 + vz·t − g·t²/2, z(T) = z_impact
+        let (vertical_speed, flight_time) = match shape {
+            BallisticShape::Apogee(apogee) => {
+                if apogee <= launch.z || apogee <= impact.z {
+                    return None;
+                }
+                let vertical_speed = (2.0 * g * (apogee - launch.z)).sqrt();
+                let flight_time = (vertical_speed + (vertical_speed.powi(2) + 2.0 * g * drop).sqrt()) / g;
+                (vertical_speed, flight_time)
+            }
+            BallisticShape::FlightTime(flight_time) => {
+                if flight_time <= 0.0 {
+                    return None;
+                }
+                ((0.5 * g * flight_time.powi(2) - drop) / flight_time, flight_time)
+            }
+        };
+
+        Some(Self {
+            launch,
+            impact,
+            initial_velocity: Velocity3D::new(
+                (impact.x - launch.x) / flight_time,
+                (impact.y - launch.y) / flight_time,
+                vertical_speed,
+            ),
+            flight_time,
+            elapsed: 0.0,
+        })
+    }
+
+    /// 経過時間における位置（高度制限なし）
+    pub fn position_at(&self, time: f64) -> Position3D {
+        Position3D {
+            x: self.launch.x + self.initial_velocity.x * time,
+            y: self.launch.y + self.initial_velocity.y * time,
+            z: self.launch.z + self.initial_velocity.z * time - 0.5 * GRAVITY_MPS2 * time.powi(2),
+        }
+    }
+
+    /// 経過時間における速度
+    pub fn velocity_at(&self, time: f64) -> Velocity3D {
+        Velocity3D::new(
+            self.initial_velocity.x,
+            self.initial_velocity.y,
+            self.initial_velocity.z - GRAVITY_MPS2 * time,
+        )
+    }
+
+    /// 着弾までの残り時間（秒）
+    pub fn time_to_impact(&self) -> f64 {
+        (self.flight_time - self.elapsed).max(0.0)
+    }
+
+    /// 着弾したか
+    pub fn has_impacted(&self) -> bool {
+        self.elapsed >= self.flight_time
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apogee_and_impact() {
+        let launch = Position3D { x: 0.0, y: 0.0, z: 0.0 };
+        let impact = Position3D { x: 300_000.0, y: 0.0, z: 0.0 };
+        let trajectory = BallisticTrajectory::new(launch, impact, BallisticShape::Apogee(80_000.0)).unwrap();
+
+        let apex = trajectory.position_at(trajectory.flight_time / 2.0);
+        assert!((apex.z - 80_000.0).abs() < 1e-6);
+        let end = trajectory.position_at(trajectory.flight_time);
+        assert!((end.x - 300_000.0).abs() < 1e-6 && end.z.abs() < 1e-6);
+
+        let timed = BallisticTrajectory::new(launch, impact, BallisticShape::FlightTime(trajectory.flight_time)).unwrap();
+        assert!((timed.initial_velocity.z - trajectory.initial_velocity.z).abs() < 1e-6);
+        assert!(BallisticTrajectory::new(launch, impact, BallisticShape::Apogee(-1.0)).is_none());
+    }
+}
@@ -3,24 +3,33 @@ use crate::models::{
     traits::{IAgent, IAllocator, IPlatform},
     common::{Position3D, AgentStatus},
     target::Target,
+    tracking::{Measurement, Track, DEFAULT_PROCESS_NOISE_MPS2},
+    fusion::SystemTrack,
 };
 
 /// 優先度付けされたターゲット情報
 /// 
 /// ターゲットの脅威度を評価するための情報を格納します。
-/// 優先度はTgo（Time-to-go）を基準とし、タイブレーカーとしてXY距離、ID順を使用します。
+/// 優先度は防護対象の価値で重み付けしたTgo（Time-to-go）を基準とし、
+/// タイブレーカーとしてXY距離、ID順を使用します。
 #[derive(Debug, Clone)]
 pub struct TargetPriority {
     /// ターゲットの一意識別子
     pub target_id: String,
-    /// Time-to-go: ターゲットが指揮所に到達するまでの予想時間（秒）
+    /// Time-to-go: ターゲットが攻撃目標の防護対象に到達するまでの予想時間（秒）
     pub tgo: f64,
-    /// XY平面での指揮所からの距離（メートル）
+    /// XY平面での攻撃目標の防護対象からの距離（メートル）
     pub distance_xy: f64,
+    /// 攻撃目標の防護対象ID
+    pub asset_id: String,
+    /// 攻撃目標の防護対象の価値
+    pub asset_value: f64,
     /// このターゲットに既に割り当てられているミサイル数
     pub assigned_missiles: u32,
     /// ターゲットの耐久値（破壊に必要なミサイル数）
     pub target_endurance: u32,
+    /// システム航跡の航跡番号（データ融合が無効な場合はNone）
+    pub track_number: Option<u32>,
 }
 
 /// 指揮所エージェント
@@ -28,6 +37,8 @@ pub struct TargetPriority {
 /// 防御システムの中央統制を行うエージェントです。
 /// センサーからのターゲット情報を基に脅威度を評価し、
 /// ランチャーに対してミサイル発射指示を出します。
+/// 雑音付き観測を受信したターゲットは航跡の推定状態で、それ以外は真値で脅威度を評価します。
+/// データ融合が有効な場合は、センサーネットワークから受信したシステム航跡ごとに脅威度を評価します。
 #[derive(Debug)]
 pub struct CommandPost {
     /// 指揮所の一意識別子
@@ -38,12 +49,26 @@ pub struct CommandPost {
     pub arrival_radius: f64,
     /// 指揮所の現在のステータス
     pub status: AgentStatus,
-    /// センサーから通知されたターゲットIDのリスト
+    /// センサーから通知されたターゲットIDのリスト（全センサーの最新の報告の和集合）
     pub detected_targets: Vec<String>,
+    /// センサーIDから最新の報告で通知されたターゲットIDへのマッピング
+    pub sensor_detections: HashMap<String, Vec<String>>,
     /// ターゲットIDからミサイルIDのリストへのマッピング
     pub missile_assignments: HashMap<String, Vec<String>>,
     /// 優先度順に並べられたターゲットのリスト
     pub target_priorities: Vec<TargetPriority>,
+    /// 防護対象IDから価値へのマッピング（未登録の防護対象の価値は1.0）
+    pub asset_values: HashMap<String, f64>,
+    /// ターゲットIDから航跡へのマッピング
+    pub tracks: HashMap<String, Track>,
+    /// 航跡フィルタのプロセス雑音（加速度の標準偏差、m/s²）
+    pub track_process_noise: f64,
+    /// 最後に報告を処理した時刻（航跡の外挿先）
+    pub current_time: f64,
+    /// システム航跡で脅威度を評価するか（データ融合が有効な場合）
+    pub track_fusion: bool,
+    /// センサーネットワークから受信したシステム航跡
+    pub system_tracks: Vec<SystemTrack>,
 }
 
 impl CommandPost {
@@ -65,24 +90,121 @@ impl CommandPost {
             arrival_radius,
             status: AgentStatus::Active,
             detected_targets: Vec::new(),
+            sensor_detections: HashMap::new(),
             missile_assignments: HashMap::new(),
             target_priorities: Vec::new(),
+            asset_values: HashMap::new(),
+            tracks: HashMap::new(),
+            track_process_noise: DEFAULT_PROCESS_NOISE_MPS2,
+            current_time: 0.0,
+            track_fusion: false,
+            system_tracks: Vec::new(),
         }
     }
 
+    /// センサーからの雑音付き観測で航跡を更新
+    /// 
+    /// 観測のあったターゲットの航跡を観測時刻順に更新（未登録の場合は開始）します。
+    /// 航跡は最後の観測時刻の状態で保持し、航跡の推定状態より古い観測（報告遅延の異なる
+    /// センサーからの順序逆転）は破棄します。
+    /// 
+    /// # 引数
+    /// 
+    /// * `measurements` - ターゲットIDと観測値の組
+    /// * `current_time` - 現在のシミュレーション時刻（秒）
+    pub fn update_tracks(&mut self, mut measurements: Vec<(String, Measurement)>, current_time: f64) {
+        measurements.sort_by(|a, b| a.1.time.total_cmp(&b.1.time));
+        for (target_id, measurement) in measurements {
+            match self.tracks.get_mut(&target_id) {
+                Some(track) if measurement.time < track.time => {}
+                Some(track) => track.update(&measurement, self.track_process_noise),
+                None => {
+                    self.tracks.insert(target_id, Track::new(&measurement));
+                }
+            }
+        }
+        self.current_time = current_time;
+    }
+
+    /// 現在時刻まで外挿した航跡の推定状態
+    /// 
+    /// # 引数
+    /// 
+    /// * `target_id` - ターゲットID
+    /// 
+    /// # 戻り値
+    /// 
+    /// 外挿した航跡、航跡がない場合はNone
+    pub fn track_estimate(&self, target_id: &str) -> Option<Track> {
+        self.tracks.get(target_id).map(|track| self.extrapolate(track))
+    }
+
+    /// 現在時刻まで外挿した全航跡の推定状態
+    /// 
+    /// # 戻り値
+    /// 
+    /// 航跡のターゲットIDと外挿した航跡の組（データ融合が有効な場合はシステム航跡ごと）
+    pub fn track_estimates(&self) -> Vec<(String, Track)> {
+        if self.track_fusion {
+            self.system_tracks
+                .iter()
+                .map(|system_track| (system_track.target_id.clone(), self.extrapolate(&system_track.track)))
+                .collect()
+        } else {
+            self.tracks
+                .iter()
+                .map(|(target_id, track)| (target_id.clone(), self.extrapolate(track)))
+                .collect()
+        }
+    }
+
+    /// 航跡を現在時刻まで外挿
+    fn extrapolate(&self, track: &Track) -> Track {
+        let mut estimate = track.clone();
+        estimate.predict(self.current_time, self.track_process_noise);
+        estimate
+    }
+
+    /// センサーネットワークからシステム航跡を受信
+    /// 
+    /// システム航跡の交戦対象のターゲットを検知リストとします。
+    /// 
+    /// # 引数
+    /// 
+    /// * `system_tracks` - 航跡番号順のシステム航跡
+    /// * `current_time` - 現在のシミュレーション時刻（秒）
+    pub fn receive_system_tracks(&mut self, system_tracks: Vec<SystemTrack>, current_time: f64) {
+        let mut detected_targets: Vec<String> = system_tracks
+            .iter()
+            .map(|system_track| system_track.target_id.clone())
+            .collect();
+        detected_targets.sort();
+        detected_targets.dedup();
+        self.detected_targets = detected_targets;
+        self.system_tracks = system_tracks;
+        self.current_time = current_time;
+    }
+
     /// センサーからのターゲット検知情報を受信
     /// 
+    /// センサーごとに最新の報告を保持し、全センサーの報告の和集合を検知リストとします。
+    /// 
     /// # 引数
     /// 
+    /// * `sensor_id` - 報告元のセンサーID
     /// * `target_ids` - 検知されたターゲットIDのリスト
-    pub fn receive_detections(&mut self, target_ids: Vec<String>) {
-        self.detected_targets = target_ids;
+    pub fn receive_detections(&mut self, sensor_id: &str, target_ids: Vec<String>) {
+        self.sensor_detections.insert(sensor_id.to_string(), target_ids);
+        let mut detected_targets: Vec<String> = self.sensor_detections.values().flatten().cloned().collect();
+        detected_targets.sort();
+        detected_targets.dedup();
+        self.detected_targets = detected_targets;
     }
 
     /// ターゲットの優先度を計算（Tgo基準）
     /// 
     /// 検知されたアクティブなターゲットに対して脅威度を計算し、
-    /// Tgo（Time-to-go）の昇順、XY距離の昇順、ID昇順でソートします。
+    /// 価値重み付きTgo（Time-to-go）の昇順、XY距離の昇順、ID昇順でソートします。
     /// 
     /// # 引数
     /// 
@@ -92,31 +214,48 @@ impl CommandPost {
 
         for target in targets {
             if self.detected_targets.contains(&target.id) && target.is_active() {
-                let tgo = self.calculate_tgo(target);
-                let distance_xy = target.position.distance_xy(&self.position);
-                let assigned_missiles = self.missile_assignments
-                    .get(&target.id)
-                    .map(|missiles| missiles.len() as u32)
-                    .unwrap_or(0);
-
-                let priority = TargetPriority {
-                    target_id: target.id.clone(),
-                    tgo,
-                    distance_xy,
-                    assigned_missiles,
-                    target_endurance: target.endurance,
-                };
-
+                let priority = self.build_priority(target, self.track_estimate(&target.id), None);
                 self.target_priorities.push(priority);
             }
         }
 
-        // 優先度でソート: Tgo昇順 → XY距離昇順 → ID昇順
+        self.sort_priorities();
+    }
+
+    /// ターゲットの優先度情報を作成（航跡がある場合は推定状態、ない場合は真値を使用）
+    /// 
+    /// 航跡がある場合も、Tgoはターゲットの経路・弾道のモデルに推定位置・速度を当てはめて計算します。
+    fn build_priority(&self, target: &Target, track: Option<Track>, track_number: Option<u32>) -> TargetPriority {
+        let (tgo, distance_xy) = match track {
+            Some(track) => (
+                target.time_to_go_from(&track.estimated_position(), Some(&track.estimated_velocity())),
+                track.estimated_position().distance_xy(&target.destination),
+            ),
+            None => (self.calculate_tgo(target), target.position.distance_xy(&target.destination)),
+        };
+        TargetPriority {
+            target_id: target.id.clone(),
+            tgo,
+            distance_xy,
+            asset_id: target.asset_id.clone(),
+            asset_value: self.asset_values.get(&target.asset_id).copied().unwrap_or(1.0),
+            assigned_missiles: self.missile_assignments
+                .get(&target.id)
+                .map(|missiles| missiles.len() as u32)
+                .unwrap_or(0),
+            target_endurance: target.endurance,
+            track_number,
+        }
+    }
+
+    /// 優先度でソート: 価値重み付きTgo（Tgo / 価値）昇順 → XY距離昇順 → ID昇順 → 航跡番号昇順
+    fn sort_priorities(&mut self) {
         self.target_priorities.sort_by(|a, b| {
-            a.tgo.partial_cmp(&b.tgo)
+            (a.tgo / a.asset_value).partial_cmp(&(b.tgo / b.asset_value))
                 .unwrap_or(std::cmp::Ordering::Equal)
                 .then(a.distance_xy.partial_cmp(&b.distance_xy).unwrap_or(std::cmp::Ordering::Equal))
                 .then(a.target_id.cmp(&b.target_id))
+                .then(a.track_number.cmp(&b.track_number))
         });
     }
 
@@ -226,40 +365,37 @@ impl CommandPost {
     /// ターゲットリストの更新
     /// 
     /// アクティブなターゲットの情報をもとに優先度リストを再構築します。
+    /// リストにないターゲットの航跡は破棄します。
+    /// データ融合が有効な場合は、リストのターゲットを交戦対象とするシステム航跡ごとに優先度を作成します
+    /// （同じターゲットの重複航跡は割り当て済みミサイル数を共有）。
     /// 
     /// # 引数
     /// 
     /// * `targets` - 更新対象のターゲットの参照ベクター
     pub fn update_target_list(&mut self, targets: Vec<&Target>) {
         self.target_priorities.clear();
+        self.tracks.retain(|target_id, _| targets.iter().any(|target| &target.id == target_id));
+        
+        if self.track_fusion {
+            for system_track in &self.system_tracks {
+                if let Some(target) = targets.iter().find(|target| target.id == system_track.target_id && target.is_active()) {
+                    let track = self.extrapolate(&system_track.track);
+                    let priority = self.build_priority(target, Some(track), Some(system_track.number));
+                    self.target_priorities.push(priority);
+                }
+            }
+            self.sort_priorities();
+            return;
+        }
         
         for target in targets {
             if target.is_active() {
-                let tgo = target.calculate_time_to_go();
-                let distance_xy = target.position.distance_xy(&self.position);
-                let assigned_missiles = self.missile_assignments
-                    .get(&target.id)
-                    .map(|missiles| missiles.len() as u32)
-                    .unwrap_or(0);
-
-                let priority = TargetPriority {
-                    target_id: target.id.clone(),
-                    tgo,
-                    distance_xy,
-                    assigned_missiles,
-                    target_endurance: target.endurance,
-                };
-
+                let priority = self.build_priority(target, self.track_estimate(&target.id), None);
                 self.target_priorities.push(priority);
             }
         }
 
-        self.target_priorities.sort_by(|a, b| {
-            a.tgo.partial_cmp(&b.tgo)
-                .unwrap_or(std::cmp::Ordering::Equal)
-                .then(a.distance_xy.partial_cmp(&b.distance_xy).unwrap_or(std::cmp::Ordering::Equal))
-                .then(a.target_id.cmp(&b.target_id))
-        });
+        self.sort_priorities();
     }
 
     /// ミサイル発射割り当てを取得
@@ -287,6 +423,30 @@ impl CommandPost {
         None
     }
 
+    /// 発射されたミサイルの割り当てを記録
+    /// 
+    /// ミサイルIDをターゲットの割り当てリストに追加し、
+    /// 同一サイクル内の優先度リストの割り当て数にも反映します。
+    /// 
+    /// # 引数
+    /// 
+    /// * `target_id` - 割り当て先のターゲットID
+    /// * `missile_id` - 発射されたミサイルのID
+    pub fn record_assignment(&mut self, target_id: &str, missile_id: String) {
+        self.missile_assignments
+            .entry(target_id.to_string())
+            .or_default()
+            .push(missile_id);
+        
+        // 同じターゲットの重複したシステム航跡にも反映
+        for priority in self.target_priorities
+            .iter_mut()
+            .filter(|priority| priority.target_id == target_id)
+        {
+            priority.assigned_missiles += 1;
+        }
+    }
+
     /// ミサイルが消滅した際の処理
     /// 
     /// 指定されたミサイルIDを割り当てリストから除去します。
@@ -311,7 +471,12 @@ impl CommandPost {
     /// * `target_id` - 消滅したターゲットのID
     pub fn on_target_destroyed(&mut self, target_id: String) {
         self.missile_assignments.remove(&target_id);
+        self.tracks.remove(&target_id);
+        self.system_tracks.retain(|system_track| system_track.target_id != target_id);
         self.detected_targets.retain(|id| id != &target_id);
+        for target_ids in self.sensor_detections.values_mut() {
+            target_ids.retain(|id| id != &target_id);
+        }
     }
 }
 
@@ -325,6 +490,19 @@ impl IAgent for CommandPost {
             // Tgoの定義に基づく計算方法を設定
         }
         // tie_breakers、launcher_selection_order、launcher_initially_cooledの設定も必要に応じて実装
+        
+        // 防護対象の価値（指揮所自身も防護対象として扱う）
+        self.asset_values = scenario_config.defended_assets
+            .iter()
+            .map(|asset| (asset.id.clone(), asset.value))
+            .collect();
+        self.asset_values.insert(self.id.clone(), scenario_config.command_post.value);
+        self.track_process_noise = scenario_config.command_post.track_process_noise_mps2;
+        self.tracks.clear();
+        self.track_fusion = scenario_config.friendly_forces.sensor_fusion.is_some();
+        self.system_tracks.clear();
+        self.sensor_detections.clear();
+        self.detected_targets.clear();
     }
 
     fn tick(&mut self, _dt: f64) {
@@ -371,4 +549,77 @@ impl IAllocator for CommandPost {
     ) -> Option<usize> {
         self.select_best_launcher(launchers, target_position)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ballistic::BallisticShape, target::Waypoint, traits::IMovable};
+
+    fn active_target(id: &str, asset_id: &str, distance: f64) -> Target {
+        let mut target = Target::new(
+            id.to_string(),
+            Position3D::new(distance, 0.0, 3000.0),
+            Position3D::new(0.0, 0.0, 0.0),
+            "G001".to_string(),
+        );
+        target.set_parameters(0.0, 1, 0.0, 100.0);
+        target.asset_id = asset_id.to_string();
+        target.check_spawn(0.0);
+        target
+    }
+
+    #[test]
+    fn test_priorities_weighted_by_asset_value() {
+        let mut command_post = CommandPost::new("CP001".to_string(), Position3D::new(0.0, 0.0, 0.0), 1000.0);
+        command_post.asset_values.insert("PORT01".to_string(), 3.0);
+
+        // CP001向けは Tgo 100秒、PORT01向けは Tgo 200秒だが価値3倍で優先
+        let near = active_target("T_CP", "CP001", 10_000.0);
+        let far = active_target("T_PORT", "PORT01", 20_000.0);
+        command_post.update_target_list(vec![&near, &far]);
+
+        let order: Vec<&str> = command_post.target_priorities.iter().map(|p| p.target_id.as_str()).collect();
+        assert_eq!(order, ["T_PORT", "T_CP"]);
+    }
+
+    /// 30秒間の観測で航跡を作り、航跡に基づくTgoを返す
+    fn tracked_tgo(target: &mut Target) -> f64 {
+        let mut command_post = CommandPost::new("CP001".to_string(), Position3D::new(0.0, 0.0, 0.0), 1000.0);
+        let covariance = [[100.0, 0.0, 0.0], [0.0, 100.0, 0.0], [0.0, 0.0, 100.0]];
+        for step in 0..=30 {
+            if step > 0 {
+                target.move_agent(1.0);
+            }
+            let position = [target.position.x, target.position.y, target.position.z];
+            let measurement = Measurement { time: step as f64, position, covariance };
+            command_post.update_tracks(vec![(target.id.clone(), measurement)], step as f64);
+        }
+        command_post.update_target_list(vec![target]);
+        command_post.target_priorities[0].tgo
+    }
+
+    #[test]
+    fn test_tracked_tgo_keeps_route_and_ballistic_models() {
+        // 経路点を経由する目標: 直線距離ではなく残りの経路で計算
+        let mut routed = active_target("T_ROUTE", "CP001", 0.0);
+        routed.destination = Position3D::new(30_000.0, 0.0, 0.0);
+        routed.set_parameters(1000.0, 1, 0.0, 200.0);
+        routed.set_route(vec![Waypoint { position: Position3D::new(0.0, 20_000.0, 3000.0), speed: 200.0 }]);
+        let tgo = tracked_tgo(&mut routed);
+        let straight_line = (routed.position.distance_xy(&routed.destination) - 1000.0) / 200.0;
+        assert!((tgo - routed.calculate_time_to_go()).abs() < 2.0, "{} {}", tgo, routed.calculate_time_to_go());
+        assert!(routed.calculate_time_to_go() - straight_line > 50.0);
+
+        // 弾道ミサイル型: 到達判定範囲に関係なく着弾までの時間
+        let mut ballistic = active_target("T_BALLISTIC", "CP001", 0.0);
+        ballistic.position.z = 0.0;
+        ballistic.destination = Position3D::new(100_000.0, 0.0, 0.0);
+        ballistic.set_parameters(10_000.0, 1, 0.0, 0.0);
+        assert!(ballistic.set_ballistic(ballistic.destination, BallisticShape::Apogee(20_000.0)));
+        let tgo = tracked_tgo(&mut ballistic);
+        let straight_line = (ballistic.position.distance_xy(&ballistic.destination) - 10_000.0) / ballistic.velocity.magnitude_xy();
+        assert!((tgo - ballistic.calculate_time_to_go()).abs() < 2.0, "{} {}", tgo, ballistic.calculate_time_to_go());
+        assert!(ballistic.calculate_time_to_go() - straight_line > 10.0);
+    }
+}
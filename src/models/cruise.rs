@@ -0,0 +1,89 @@
+//! # Cruise モジュール
+//!
+//! 巡航ミサイル型の敵ターゲットが従う低高度飛行プロファイルを定義します。
+//!
+//! 巡航ミサイルは地表からの一定高度（対地高度）を保って飛行し、
+//! 目的地の手前（終末段階）でポップアップまたはダイブを行います。
+//! 地形モデルは持たないため、地表は高度0の平坦地形とみなします。
+//!
+//! ## 終末機動
+//!
+//! - **なし（None）**: 巡航高度のまま目的地へ進入
+//! - **ポップアップ（PopUp）**: 終末距離の前半で `pop_up_altitude` まで上昇し、後半で目的地へ降下
+//! - **ダイブ（Dive）**: 終末距離内で巡航高度から目的地へ直線的に降下
+
+/// 終末段階の機動
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TerminalManeuver {
+    /// 巡航高度のまま進入
+    None,
+    /// 上昇してから降下
+    PopUp,
+    /// 巡航高度から降下
+    Dive,
+}
+
+/// 巡航ミサイルの飛行プロファイル
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CruiseProfile {
+    /// 巡航時の対地高度（メートル）
+    pub cruise_altitude: f64,
+    /// 終末段階の機動
+    pub terminal: TerminalManeuver,
+    /// 終末段階を開始する目的地までの水平距離（メートル）
+    pub terminal_range: f64,
+    /// ポップアップ時の最高高度（メートル）
+    pub pop_up_altitude: f64,
+}
+
+impl CruiseProfile {
+    /// 目的地までの水平距離に応じた目標高度
+    ///
+    /// # 引数
+    ///
+    /// * `distance_to_destination` - 目的地までの水平距離（メートル）
+    ///
+    /// # 戻り値
+    ///
+    /// 目標高度（メートル、地表高度0を基準）
+    pub fn altitude_at(&self, distance_to_destination: f64) -> f64 {
+        if self.terminal_range <= 0.0 || distance_to_destination >= self.terminal_range {
+            return self.cruise_altitude;
+        }
+        let progress = distance_to_destination / self.terminal_range;
+        match self.terminal {
+            TerminalManeuver::None => self.cruise_altitude,
+            TerminalManeuver::Dive => self.cruise_altitude * progress,
+            TerminalManeuver::PopUp => {
+                if progress >= 0.5 {
+                    // 終末距離の前半: 巡航高度からポップアップ高度へ上昇
+                    let climb = (1.0 - progress) * 2.0;
+                    self.cruise_altitude + (self.pop_up_altitude - self.cruise_altitude) * climb
+                } else {
+                    // 後半: ポップアップ高度から目的地へ降下
+                    self.pop_up_altitude * progress * 2.0
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pop_up_profile() {
+        let profile = CruiseProfile {
+            cruise_altitude: 30.0,
+            terminal: TerminalManeuver::PopUp,
+            terminal_range: 10_000.0,
+            pop_up_altitude: 1_500.0,
+        };
+        assert_eq!(profile.altitude_at(50_000.0), 30.0);
+        assert_eq!(profile.altitude_at(10_000.0), 30.0);
+        assert_eq!(profile.altitude_at(5_000.0), 1_500.0);
+        assert_eq!(profile.altitude_at(0.0), 0.0);
+        assert!(profile.altitude_at(7_500.0) > 30.0 && profile.altitude_at(2_500.0) < 1_500.0);
+    }
+}
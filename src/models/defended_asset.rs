@@ -0,0 +1,40 @@
+//! # DefendedAsset モジュール
+//!
+//! 敵ターゲットの攻撃目標となる防護対象（航空基地・港湾など）を定義します。
+//!
+//! 各敵グループは防護対象を1つ指定して進攻し、到達判定範囲に入った時点で
+//! その防護対象への突破として扱われます。防護対象を指定しないグループは
+//! 従来どおり指揮所を目標とします。価値（`value`）は指揮所の目標優先度判定で
+//! Tgoの重み付けに使用され、価値の高い防護対象を狙う脅威ほど優先されます。
+
+use crate::models::common::Position3D;
+
+/// 防護対象
+#[derive(Debug, Clone, PartialEq)]
+pub struct DefendedAsset {
+    /// 防護対象の一意識別子
+    pub id: String,
+    /// 種別（airbase, port など）
+    pub kind: String,
+    /// 防護対象の位置（地上）
+    pub position: Position3D,
+    /// 突破判定に使用される到達範囲（メートル）
+    pub arrival_radius: f64,
+    /// 防護対象の価値（優先度の重み）
+    pub value: f64,
+}
+
+impl DefendedAsset {
+    /// 新しい防護対象を作成
+    ///
+    /// # 引数
+    ///
+    /// * `id` - 防護対象の一意識別子
+    /// * `kind` - 種別
+    /// * `position` - 位置
+    /// * `arrival_radius` - 到達判定範囲（メートル）
+    /// * `value` - 価値
+    pub fn new(id: String, kind: String, position: Position3D, arrival_radius: f64, value: f64) -> Self {
+        Self { id, kind, position, arrival_radius, value }
+    }
+}
@@ -0,0 +1,145 @@
+//! # Detection モジュール
+//!
+//! センサーの確率的な探知モデル（探知確率 Pd と M-of-N 航跡確立）を定義します。
+//!
+//! 各観測（走査）ごとに、ターゲットのSNRから求めた単一観測の探知確率で探知の成否を判定します。
+//! SNRはレーダー方程式に従い距離の4乗に反比例し、RCSに比例します。
+//! 探知しきい値は誤警報確率から定め（CFAR、しきい値を直接指定した場合はその値）、
+//! Swerling I 型ターゲットの単一観測探知確率 `Pd = exp(−しきい値 / (1 + SNR))`
+//! （誤警報確率から定めた場合は `Pfa^(1 / (1 + SNR))`）を用います。
+//!
+//! 探知の判定は実効探知範囲に限らず、単一観測の探知確率が `MIN_SINGLE_LOOK_PD` を下回る距離
+//! （計測範囲）まで行います。誤警報確率はしきい値の決定にのみ使用し、誤警報（存在しない目標の探知）は生成しません。
+//!
+//! ## 航跡の確立と喪失
+//!
+//! - **確立**: 直近N回の観測のうちM回以上探知した時点で初回検知とする
+//! - **喪失**: 直近N回の観測で一度も探知しなかった時点でロストとする
+
+/// 探知判定を行う単一観測の探知確率の下限（これを下回る距離では探知しない）
+pub const MIN_SINGLE_LOOK_PD: f64 = 1e-3;
+
+/// 確率的な探知モデル
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DetectionProbability {
+    /// 実効探知範囲でのSNR（真数）
+    pub reference_snr: f64,
+    /// 誤警報確率
+    pub false_alarm_probability: f64,
+    /// 探知しきい値（雑音電力で正規化、指定時は誤警報確率から定めた値に代えて使用）
+    pub threshold: Option<f64>,
+    /// 航跡確立に必要な探知回数（M）
+    pub confirm_hits: u32,
+    /// 航跡確立を判定する観測回数（N、32以下）
+    pub confirm_looks: u32,
+}
+
+impl DetectionProbability {
+    /// 距離に応じたSNR（真数）
+    ///
+    /// # 引数
+    ///
+    /// * `range` - ターゲットまでの距離（メートル）
+    /// * `effective_range` - ターゲットのRCSに対する実効探知範囲（メートル、SNRが `reference_snr` となる距離）
+    pub fn snr(&self, range: f64, effective_range: f64) -> f64 {
+        self.reference_snr * (effective_range / range.max(1.0)).powi(4)
+    }
+
+    /// 探知しきい値（雑音電力で正規化、未指定の場合は誤警報確率から定める）
+    pub fn threshold(&self) -> f64 {
+        self.threshold.unwrap_or_else(|| -self.false_alarm_probability.ln())
+    }
+
+    /// 単一観測の探知確率
+    ///
+    /// # 引数
+    ///
+    /// * `range` - ターゲットまでの距離（メートル）
+    /// * `effective_range` - ターゲットのRCSに対する実効探知範囲（メートル）
+    pub fn single_look_pd(&self, range: f64, effective_range: f64) -> f64 {
+        (-self.threshold() / (1.0 + self.snr(range, effective_range))).exp()
+    }
+
+    /// 計測範囲（単一観測の探知確率が `MIN_SINGLE_LOOK_PD` となる距離）
+    ///
+    /// 誤警報確率が下限以上の場合は探知確率が下限を下回らないため、SNRが十分小さくなる距離とします。
+    ///
+    /// # 引数
+    ///
+    /// * `effective_range` - ターゲットのRCSに対する実効探知範囲（メートル）
+    pub fn instrumented_range(&self, effective_range: f64) -> f64 {
+        let minimum_snr = (self.threshold() / -MIN_SINGLE_LOOK_PD.ln() - 1.0).max(1e-3);
+        effective_range * (self.reference_snr / minimum_snr).powf(0.25)
+    }
+}
+
+/// ターゲットごとの観測結果の履歴（直近32回）
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LookHistory {
+    /// 観測結果のビット列（最下位ビットが最新、1が探知）
+    hits: u32,
+}
+
+impl LookHistory {
+    /// 観測結果を記録
+    pub fn record(&mut self, hit: bool) {
+        self.hits = (self.hits << 1) | hit as u32;
+    }
+
+    /// 直近 `looks` 回の観測での探知回数
+    pub fn hits_in_last(&self, looks: u32) -> u32 {
+        let mask = if looks >= 32 { u32::MAX } else { (1 << looks) - 1 };
+        (self.hits & mask).count_ones()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pd_falls_with_range_and_m_of_n_window() {
+        let model = DetectionProbability {
+            reference_snr: 10f64.powf(1.3),
+            false_alarm_probability: 1e-6,
+            threshold: None,
+            confirm_hits: 2,
+            confirm_looks: 3,
+        };
+        let at_range = model.single_look_pd(100_000.0, 100_000.0);
+        assert!((at_range - 1e-6f64.powf(1.0 / (1.0 + 10f64.powf(1.3)))).abs() < 1e-12);
+        assert!(model.single_look_pd(50_000.0, 100_000.0) > 0.95);
+        assert!(model.single_look_pd(200_000.0, 100_000.0) < 0.01);
+        // 実効探知範囲を超えても計測範囲までは探知確率が連続的に低下
+        let instrumented = model.instrumented_range(100_000.0);
+        assert!(instrumented > 100_000.0);
+        assert!((model.single_look_pd(instrumented, 100_000.0) - MIN_SINGLE_LOOK_PD).abs() < 1e-9);
+
+        let mut history = LookHistory::default();
+        for hit in [true, false, false, true, true] {
+            history.record(hit);
+        }
+        assert_eq!(history.hits_in_last(3), 2);
+        assert_eq!(history.hits_in_last(32), 3);
+    }
+
+    #[test]
+    fn test_threshold_overrides_false_alarm_probability() {
+        let model = DetectionProbability {
+            reference_snr: 20.0,
+            false_alarm_probability: 1e-6,
+            threshold: None,
+            confirm_hits: 1,
+            confirm_looks: 1,
+        };
+        // 誤警報確率 1e-4 相当のしきい値は、誤警報確率 1e-4 と同じ探知確率になる
+        let lowered = DetectionProbability { threshold: Some(-1e-4f64.ln()), ..model };
+        let by_pfa = DetectionProbability { false_alarm_probability: 1e-4, ..model };
+        assert!((lowered.single_look_pd(120_000.0, 100_000.0) - by_pfa.single_look_pd(120_000.0, 100_000.0)).abs() < 1e-12);
+        assert!(lowered.single_look_pd(120_000.0, 100_000.0) > model.single_look_pd(120_000.0, 100_000.0));
+        assert!(lowered.instrumented_range(100_000.0) > model.instrumented_range(100_000.0));
+        // しきい値を上げると探知確率が下がる
+        let raised = DetectionProbability { threshold: Some(20.0), ..model };
+        assert!(raised.single_look_pd(120_000.0, 100_000.0) < model.single_look_pd(120_000.0, 100_000.0));
+    }
+}
@@ -0,0 +1,344 @@
+//! # Fusion モジュール
+//!
+//! 複数センサーの観測を相関・融合したシステム航跡を定義します。
+//!
+//! センサーの報告に含まれる雑音付き観測を既存のシステム航跡とゲーティングと最近傍法で相関し、
+//! 相関した航跡をカルマンフィルタで更新します。相関する航跡がない観測からは新しいシステム航跡を開始し、
+//! 開始順に一意の航跡番号を割り当てます（番号は再利用しません）。
+//!
+//! ## 相関の規則
+//!
+//! - **ゲート**: 観測時刻に外挿した航跡とのマハラノビス距離の2乗が `gate` 以下
+//! - **最近傍**: ゲート内の航跡のうち距離が最小のものに相関（1つの報告で同じ航跡に相関するのは1観測のみ）
+//! - **統合**: 推定位置の差のマハラノビス距離の2乗が `gate` 以下の航跡は番号の小さい航跡に統合。
+//!   ただし更新回数が `MIN_MERGE_UPDATES` 未満の航跡と、同じ報告で更新された（別の目標と分かっている）航跡の組は統合しない
+//! - **消去**: 最後の更新から `coast_time` を超えて観測のない航跡は消去
+//!
+//! ゲートを棄却域の確率で外れた観測からも航跡を開始するため、同じ目標の重複航跡は統合で解消します。
+//! 撃破したターゲットの観測（報告遅延で撃破後に届いたもの）は航跡を開始しません。
+//!
+//! シミュレーション上の交戦対象とするため、システム航跡は開始時の観測のターゲットIDを保持します。
+//! 相関の判定にはターゲットIDを使用せず、異なるターゲットの観測との相関は誤相関として集計します。
+
+use std::collections::{BTreeSet, HashSet};
+use tracing::info;
+use crate::models::tracking::{Measurement, Track};
+
+/// ゲートの既定値（自由度3のカイ二乗分布の99.9%点）
+pub const DEFAULT_GATE: f64 = 16.27;
+
+/// システム航跡を消去するまでの観測のない時間の既定値（秒）
+pub const DEFAULT_COAST_TIME_S: f64 = 10.0;
+
+/// 重複として統合する航跡の最小更新回数（開始直後の共分散の大きい航跡は統合しない）
+pub const MIN_MERGE_UPDATES: u32 = 3;
+
+/// 複数センサーの観測を融合したシステム航跡
+#[derive(Debug, Clone, PartialEq)]
+pub struct SystemTrack {
+    /// 航跡番号
+    pub number: u32,
+    /// 航跡開始時の観測のターゲットID（交戦対象）
+    pub target_id: String,
+    /// 融合した推定状態
+    pub track: Track,
+    /// 観測が相関したセンサーのID
+    pub sensors: BTreeSet<String>,
+}
+
+/// 航跡融合の統計
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FusionStats {
+    /// 処理した観測数
+    pub measurements: u32,
+    /// 既存の航跡に相関した観測数
+    pub associated: u32,
+    /// 航跡のターゲットと異なるターゲットの観測が相関した数
+    pub misassociated: u32,
+    /// 開始したシステム航跡数
+    pub initiated: u32,
+    /// 重複として統合したシステム航跡数
+    pub merged: u32,
+    /// 観測がなく消去したシステム航跡数
+    pub coasted_out: u32,
+    /// 同じターゲットに重複したシステム航跡数の最大値
+    pub peak_duplicates: usize,
+}
+
+/// システム航跡の管理
+#[derive(Debug, Clone)]
+pub struct SystemTracker {
+    /// 航跡番号順のシステム航跡
+    pub tracks: Vec<SystemTrack>,
+    /// 相関のゲート（マハラノビス距離の2乗）
+    pub gate: f64,
+    /// 観測のない航跡を消去するまでの時間（秒）
+    pub coast_time: f64,
+    /// 航跡フィルタのプロセス雑音（加速度の標準偏差、m/s²）
+    pub process_noise: f64,
+    /// 航跡融合の統計
+    pub stats: FusionStats,
+    /// 次に割り当てる航跡番号
+    next_number: u32,
+    /// 撃破されたターゲットのID
+    destroyed: HashSet<String>,
+    /// 同じ報告で更新された航跡番号の組（小さい番号, 大きい番号）
+    resolved: HashSet<(u32, u32)>,
+}
+
+impl SystemTracker {
+    /// 新しいシステム航跡の管理を作成
+    ///
+    /// # 引数
+    ///
+    /// * `gate` - 相関のゲート（マハラノビス距離の2乗）
+    /// * `coast_time` - 観測のない航跡を消去するまでの時間（秒）
+    /// * `process_noise` - 加速度の標準偏差（m/s²）
+    pub fn new(gate: f64, coast_time: f64, process_noise: f64) -> Self {
+        Self {
+            tracks: Vec::new(),
+            gate,
+            coast_time,
+            process_noise,
+            stats: FusionStats::default(),
+            next_number: 1,
+            destroyed: HashSet::new(),
+            resolved: HashSet::new(),
+        }
+    }
+
+    /// センサーの報告に含まれる観測を相関・融合
+    ///
+    /// 航跡の推定状態より古い観測は相関のみ行い、推定状態は更新しません。
+    ///
+    /// # 引数
+    ///
+    /// * `sensor_id` - 報告元のセンサーID
+    /// * `measurements` - ターゲットIDと観測値の組
+    pub fn fuse(&mut self, sensor_id: &str, mut measurements: Vec<(String, Measurement)>) {
+        measurements.sort_by(|a, b| a.1.time.total_cmp(&b.1.time));
+        // この報告の観測が相関済みの航跡番号
+        let mut updated = HashSet::new();
+        for (target_id, measurement) in measurements {
+            if self.destroyed.contains(&target_id) {
+                continue;
+            }
+            self.stats.measurements += 1;
+            let nearest = self.tracks
+                .iter()
+                .enumerate()
+                .filter(|(_, system_track)| !updated.contains(&system_track.number))
+                .filter_map(|(index, system_track)| {
+                    system_track.track
+                        .gating_distance(&measurement, self.process_noise)
+                        .map(|distance| (index, distance))
+                })
+                .filter(|(_, distance)| *distance <= self.gate)
+                .min_by(|a, b| a.1.total_cmp(&b.1));
+
+            match nearest {
+                Some((index, _)) => {
+                    let system_track = &mut self.tracks[index];
+                    if measurement.time >= system_track.track.time {
+                        system_track.track.update(&measurement, self.process_noise);
+                    }
+                    system_track.sensors.insert(sensor_id.to_string());
+                    updated.insert(system_track.number);
+                    self.stats.associated += 1;
+                    if system_track.target_id != target_id {
+                        self.stats.misassociated += 1;
+                    }
+                }
+                None => {
+                    let number = self.next_number;
+                    self.next_number += 1;
+                    info!(track_number = number, target_id = %target_id, sensor_id = %sensor_id,
+                          "SYSTEM_TRACK_INITIATED: システム航跡を開始しました");
+                    self.tracks.push(SystemTrack {
+                        number,
+                        target_id,
+                        track: Track::new(&measurement),
+                        sensors: BTreeSet::from([sensor_id.to_string()]),
+                    });
+                    updated.insert(number);
+                    self.stats.initiated += 1;
+                }
+            }
+        }
+
+        // 1つの報告の観測はそれぞれ別の目標のものなので、同じ報告で更新された航跡は統合の対象外
+        let mut numbers: Vec<u32> = updated.into_iter().collect();
+        numbers.sort_unstable();
+        for (i, &lower) in numbers.iter().enumerate() {
+            for &higher in &numbers[i + 1..] {
+                self.resolved.insert((lower, higher));
+            }
+        }
+    }
+
+    /// 重複したシステム航跡を統合し、観測のない航跡を消去して重複航跡数を集計
+    ///
+    /// # 引数
+    ///
+    /// * `current_time` - 現在のシミュレーション時刻（秒）
+    pub fn prune(&mut self, current_time: f64) {
+        self.merge_duplicates();
+
+        let coast_time = self.coast_time;
+        let before = self.tracks.len();
+        self.tracks.retain(|system_track| {
+            let keep = current_time - system_track.track.time <= coast_time;
+            if !keep {
+                info!(track_number = system_track.number, target_id = %system_track.target_id,
+                      "SYSTEM_TRACK_DROPPED: 観測のないシステム航跡を消去しました");
+            }
+            keep
+        });
+        self.stats.coasted_out += (before - self.tracks.len()) as u32;
+        self.forget_removed_tracks();
+
+        let targets: HashSet<&str> = self.tracks.iter().map(|system_track| system_track.target_id.as_str()).collect();
+        self.stats.peak_duplicates = self.stats.peak_duplicates.max(self.tracks.len() - targets.len());
+    }
+
+    /// 撃破されたターゲットを交戦対象とするシステム航跡を消去
+    ///
+    /// # 引数
+    ///
+    /// * `target_id` - 撃破されたターゲットのID
+    pub fn remove_target(&mut self, target_id: &str) {
+        self.tracks.retain(|system_track| system_track.target_id != target_id);
+        self.destroyed.insert(target_id.to_string());
+        self.forget_removed_tracks();
+    }
+
+    /// 消去した航跡を含む航跡番号の組を破棄
+    fn forget_removed_tracks(&mut self) {
+        let numbers: HashSet<u32> = self.tracks.iter().map(|system_track| system_track.number).collect();
+        self.resolved.retain(|(lower, higher)| numbers.contains(lower) && numbers.contains(higher));
+    }
+
+    /// ゲート内で重なる航跡を番号の小さい航跡に統合
+    ///
+    /// 更新回数が `MIN_MERGE_UPDATES` 未満の航跡と、同じ報告で更新された航跡の組は統合しません。
+    fn merge_duplicates(&mut self) {
+        let mut index = 1;
+        while index < self.tracks.len() {
+            let candidate = &self.tracks[index];
+            let duplicate_of = (0..index).find(|&kept| {
+                let kept = &self.tracks[kept];
+                kept.track.updates >= MIN_MERGE_UPDATES
+                    && candidate.track.updates >= MIN_MERGE_UPDATES
+                    && !self.resolved.contains(&(kept.number, candidate.number))
+                    && kept.track
+                        .track_distance(&candidate.track, self.process_noise)
+                        .is_some_and(|distance| distance <= self.gate)
+            });
+            match duplicate_of {
+                Some(kept) => {
+                    let duplicate = self.tracks.remove(index);
+                    info!(track_number = duplicate.number, merged_into = self.tracks[kept].number,
+                          "SYSTEM_TRACK_MERGED: 重複したシステム航跡を統合しました");
+                    self.tracks[kept].sensors.extend(duplicate.sensors);
+                    self.stats.merged += 1;
+                }
+                None => index += 1,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(time: f64, x: f64, y: f64) -> Measurement {
+        noisy_measurement(time, x, y, 100.0)
+    }
+
+    fn noisy_measurement(time: f64, x: f64, y: f64, sigma: f64) -> Measurement {
+        let variance = sigma.powi(2);
+        Measurement {
+            time,
+            position: [x, y, 1000.0],
+            covariance: [[variance, 0.0, 0.0], [0.0, variance, 0.0], [0.0, 0.0, variance]],
+        }
+    }
+
+    #[test]
+    fn test_two_sensors_fuse_into_numbered_system_tracks() {
+        let mut tracker = SystemTracker::new(DEFAULT_GATE, DEFAULT_COAST_TIME_S, 10.0);
+        tracker.fuse("S001", vec![
+            ("T001".to_string(), measurement(0.0, 0.0, 0.0)),
+            ("T002".to_string(), measurement(0.0, 50_000.0, 0.0)),
+        ]);
+        // 別のセンサーによる同じターゲットの観測は既存の航跡に相関
+        tracker.fuse("S002", vec![
+            ("T002".to_string(), measurement(1.0, 50_100.0, 50.0)),
+            ("T001".to_string(), measurement(1.0, 80.0, -40.0)),
+        ]);
+        let numbers: Vec<(u32, &str, usize)> = tracker.tracks
+            .iter()
+            .map(|t| (t.number, t.target_id.as_str(), t.sensors.len()))
+            .collect();
+        assert_eq!(numbers, vec![(1, "T001", 2), (2, "T002", 2)]);
+        assert_eq!((tracker.stats.associated, tracker.stats.misassociated), (2, 0));
+
+        // ゲート外の観測は新しい航跡番号で開始し、撃破・観測途絶で消去
+        tracker.fuse("S001", vec![("T003".to_string(), measurement(5.0, 0.0, 30_000.0))]);
+        assert_eq!(tracker.tracks.last().map(|t| t.number), Some(3));
+        tracker.remove_target("T003");
+        tracker.fuse("S001", vec![("T001".to_string(), measurement(8.0, 0.0, 0.0))]);
+        tracker.prune(12.0);
+        assert_eq!(tracker.tracks.iter().map(|t| t.number).collect::<Vec<_>>(), vec![1]);
+        assert_eq!(tracker.stats.coasted_out, 1);
+    }
+
+    #[test]
+    fn test_duplicate_system_tracks_merge_into_lower_number() {
+        let mut tracker = SystemTracker::new(DEFAULT_GATE, DEFAULT_COAST_TIME_S, 10.0);
+        tracker.fuse("S001", vec![("T001".to_string(), measurement(0.0, 0.0, 0.0))]);
+        // ゲート外の観測から同じターゲットの重複航跡が開始される
+        tracker.fuse("S002", vec![("T001".to_string(), measurement(0.0, 700.0, 0.0))]);
+        tracker.prune(0.0);
+        assert_eq!(tracker.tracks.len(), 2);
+
+        // 別々の報告で更新を重ねた航跡の推定が重なると番号の小さい航跡に統合
+        for (time, x) in [(1.0, 350.0), (2.0, 100.0), (3.0, 0.0)] {
+            tracker.fuse("S001", vec![("T001".to_string(), measurement(time, 0.0, 0.0))]);
+            tracker.fuse("S002", vec![("T001".to_string(), measurement(time, x, 0.0))]);
+            tracker.prune(time);
+        }
+        assert_eq!(tracker.tracks.len(), 1);
+        assert_eq!(tracker.tracks[0].number, 1);
+        assert_eq!(tracker.tracks[0].sensors.len(), 2);
+        assert_eq!(tracker.stats.merged, 1);
+
+        // 撃破後に届いた観測からは航跡を開始しない
+        tracker.remove_target("T001");
+        tracker.fuse("S002", vec![("T001".to_string(), measurement(0.5, 1200.0, 0.0))]);
+        assert!(tracker.tracks.is_empty());
+    }
+
+    #[test]
+    fn test_closely_spaced_targets_keep_separate_tracks() {
+        let mut tracker = SystemTracker::new(DEFAULT_GATE, DEFAULT_COAST_TIME_S, 10.0);
+        // 観測誤差（400m）に比べて間隔の狭い（1km）2機を2つのセンサーが毎秒観測
+        for step in 0..20 {
+            let time = step as f64;
+            for sensor_id in ["S001", "S002"] {
+                tracker.fuse(sensor_id, vec![
+                    ("T001".to_string(), noisy_measurement(time, 200.0 * time, 0.0, 400.0)),
+                    ("T002".to_string(), noisy_measurement(time, 200.0 * time, 1000.0, 400.0)),
+                ]);
+            }
+            tracker.prune(time);
+        }
+        let tracks: Vec<(u32, &str)> = tracker.tracks
+            .iter()
+            .map(|t| (t.number, t.target_id.as_str()))
+            .collect();
+        assert_eq!(tracks, vec![(1, "T001"), (2, "T002")]);
+        assert_eq!(tracker.stats.merged, 0);
+    }
+}
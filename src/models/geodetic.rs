@@ -0,0 +1,338 @@
+//! # Geodetic モジュール
+//!
+//! シミュレーション内部のローカル直交座標系（X=東, Y=北, Z=上）と
+//! WGS84 測地座標系・地心直交座標系（ECEF）との相互変換を提供します。
+//!
+//! ローカル座標系は、基準点（緯度・経度・高度）を原点とする
+//! ENU（East-North-Up）接平面座標系として扱います。
+
+use crate::models::common::{Position3D, Velocity3D, math_utils};
+
+/// WGS84 長半径（メートル）
+pub const WGS84_A: f64 = 6_378_137.0;
+/// WGS84 扁平率
+pub const WGS84_F: f64 = 1.0 / 298.257_223_563;
+/// WGS84 第一離心率の2乗
+pub const WGS84_E2: f64 = WGS84_F * (2.0 - WGS84_F);
+
+/// 測地座標（WGS84）
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeodeticPosition {
+    /// 緯度（度、北緯が正）
+    pub latitude_deg: f64,
+    /// 経度（度、東経が正）
+    pub longitude_deg: f64,
+    /// 楕円体高（メートル）
+    pub altitude_m: f64,
+}
+
+/// 地心直交座標（ECEF、メートル）
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EcefPosition {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl GeodeticPosition {
+    /// 新しい測地座標を作成
+    ///
+    /// # 引数
+    ///
+    /// * `latitude_deg` - 緯度（度）
+    /// * `longitude_deg` - 経度（度）
+    /// * `altitude_m` - 楕円体高（メートル）
+    pub fn new(latitude_deg: f64, longitude_deg: f64, altitude_m: f64) -> Self {
+        Self { latitude_deg, longitude_deg, altitude_m }
+    }
+
+    /// ECEF座標に変換
+    pub fn to_ecef(self) -> EcefPosition {
+        let lat = math_utils::deg_to_rad(self.latitude_deg);
+        let lon = math_utils::deg_to_rad(self.longitude_deg);
+        let n = WGS84_A / (1.0 - WGS84_E2 * lat.sin().powi(2)).sqrt();
+
+        EcefPosition {
+            x: (n + self.altitude_m) * lat.cos() * lon.cos(),
+            y: (n + self.altitude_m) * lat.cos() * lon.sin(),
+            z: (n * (1.0 - WGS84_E2) + self.altitude_m) * lat.sin(),
+        }
+    }
+}
+
+impl std::str::FromStr for GeodeticPosition {
+    type Err = String;
+
+    /// `"緯度,経度[,高度]"` 形式の文字列を解析
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let values: Vec<f64> = s
+            .split(',')
+            .map(|v| v.trim().parse::<f64>())
+            .collect::<Result<_, _>>()
+            .map_err(|_| format!("無効な測地座標: {}. 形式: 緯度,経度[,高度]", s))?;
+
+        let (latitude_deg, longitude_deg, altitude_m) = match values.as_slice() {
+            [lat, lon] => (*lat, *lon, 0.0),
+            [lat, lon, alt] => (*lat, *lon, *alt),
+            _ => return Err(format!("無効な測地座標: {}. 形式: 緯度,経度[,高度]", s)),
+        };
+
+        if !(-90.0..=90.0).contains(&latitude_deg) || !(-180.0..=180.0).contains(&longitude_deg) {
+            return Err(format!("緯度・経度が範囲外です: {}", s));
+        }
+
+        Ok(Self::new(latitude_deg, longitude_deg, altitude_m))
+    }
+}
+
+impl EcefPosition {
+    /// 測地座標に変換（Bowringの反復法）
+    pub fn to_geodetic(self) -> GeodeticPosition {
+        let p = (self.x.powi(2) + self.y.powi(2)).sqrt();
+        let lon = self.y.atan2(self.x);
+
+        // 初期値は球面近似
+        let mut lat = self.z.atan2(p * (1.0 - WGS84_E2));
+        let mut alt = 0.0;
+        for _ in 0..5 {
+            let n = WGS84_A / (1.0 - WGS84_E2 * lat.sin().powi(2)).sqrt();
+            alt = if lat.cos().abs() > 1e-12 {
+                p / lat.cos() - n
+            } else {
+                self.z.abs() - n * (1.0 - WGS84_E2)
+            };
+            lat = self.z.atan2(p * (1.0 - WGS84_E2 * n / (n + alt)));
+        }
+
+        GeodeticPosition::new(math_utils::rad_to_deg(lat), math_utils::rad_to_deg(lon), alt)
+    }
+}
+
+/// ローカルENU座標系の基準点
+///
+/// シミュレーション座標（X=東, Y=北, Z=上）を基準点周りの
+/// 接平面とみなし、測地座標・ECEF座標への変換を行います。
+#[derive(Debug, Clone, Copy)]
+pub struct GeodeticReference {
+    origin_altitude_m: f64,
+    origin_ecef: EcefPosition,
+    sin_lat: f64,
+    cos_lat: f64,
+    sin_lon: f64,
+    cos_lon: f64,
+}
+
+impl GeodeticReference {
+    /// 基準点から変換器を作成
+    ///
+    /// # 引数
+    ///
+    /// * `origin` - ローカル座標原点の測地座標
+    pub fn new(origin: GeodeticPosition) -> Self {
+        let lat = math_utils::deg_to_rad(origin.latitude_deg);
+        let lon = math_utils::deg_to_rad(origin.longitude_deg);
+        Self {
+            origin_altitude_m: origin.altitude_m,
+            origin_ecef: origin.to_ecef(),
+            sin_lat: lat.sin(),
+            cos_lat: lat.cos(),
+            sin_lon: lon.sin(),
+            cos_lon: lon.cos(),
+        }
+    }
+
+    /// ENUベクトルをECEFベクトルに回転（平行移動なし）
+    fn rotate_enu_to_ecef(&self, e: f64, n: f64, u: f64) -> (f64, f64, f64) {
+        (
+            -self.sin_lon * e - self.sin_lat * self.cos_lon * n + self.cos_lat * self.cos_lon * u,
+            self.cos_lon * e - self.sin_lat * self.sin_lon * n + self.cos_lat * self.sin_lon * u,
+            self.cos_lat * n + self.sin_lat * u,
+        )
+    }
+
+    /// ローカル座標をECEF座標に変換
+    pub fn enu_to_ecef(&self, position: &Position3D) -> EcefPosition {
+        let (dx, dy, dz) = self.rotate_enu_to_ecef(position.x, position.y, position.z);
+        EcefPosition {
+            x: self.origin_ecef.x + dx,
+            y: self.origin_ecef.y + dy,
+            z: self.origin_ecef.z + dz,
+        }
+    }
+
+    /// ECEF座標をローカルENU座標に変換
+    pub fn ecef_to_enu(&self, position: &EcefPosition) -> Position3D {
+        let dx = position.x - self.origin_ecef.x;
+        let dy = position.y - self.origin_ecef.y;
+        let dz = position.z - self.origin_ecef.z;
+        Position3D {
+            x: -self.sin_lon * dx + self.cos_lon * dy,
+            y: -self.sin_lat * self.cos_lon * dx - self.sin_lat * self.sin_lon * dy + self.cos_lat * dz,
+            z: self.cos_lat * self.cos_lon * dx + self.cos_lat * self.sin_lon * dy + self.sin_lat * dz,
+        }
+    }
+
+    /// ローカル速度ベクトルをECEF速度ベクトルに変換
+    pub fn enu_velocity_to_ecef(&self, velocity: &Velocity3D) -> (f64, f64, f64) {
+        self.rotate_enu_to_ecef(velocity.x, velocity.y, velocity.z)
+    }
+
+    /// ローカル座標を測地座標に変換
+    pub fn enu_to_geodetic(&self, position: &Position3D) -> GeodeticPosition {
+        self.enu_to_ecef(position).to_geodetic()
+    }
+
+    /// ローカル座標を高度保持で測地座標に変換
+    ///
+    /// シミュレーションは平面近似のため、水平位置のみ接平面から投影し、
+    /// 高度は基準点高度にローカルZ座標を加えた値とします。
+    /// 基準点から離れても地球の丸みによる見かけの高度上昇が生じません。
+    pub fn local_to_geodetic(&self, position: &Position3D) -> GeodeticPosition {
+        let surface = self.enu_to_geodetic(&Position3D::new(position.x, position.y, 0.0));
+        GeodeticPosition::new(
+            surface.latitude_deg,
+            surface.longitude_deg,
+            self.origin_altitude_m + position.z,
+        )
+    }
+
+    /// 測地座標を高度保持でローカル座標に変換（`local_to_geodetic`の逆変換）
+    ///
+    /// 水平位置は接平面上の点を反復補正して求め、
+    /// Z座標は基準点高度からの差とします。
+    pub fn geodetic_to_local(&self, position: &GeodeticPosition) -> Position3D {
+        let surface = GeodeticPosition::new(position.latitude_deg, position.longitude_deg, self.origin_altitude_m);
+        let goal = self.ecef_to_enu(&surface.to_ecef());
+
+        let (mut x, mut y) = (goal.x, goal.y);
+        for _ in 0..5 {
+            let projected = self.local_to_geodetic(&Position3D::new(x, y, 0.0));
+            let current = self.ecef_to_enu(&projected.to_ecef());
+            x += goal.x - current.x;
+            y += goal.y - current.y;
+        }
+
+        Position3D { x, y, z: position.altitude_m - self.origin_altitude_m }
+    }
+
+    /// ローカル姿勢をECEF基準のオイラー角（DIS規約 psi, theta, phi、ラジアン）に変換
+    ///
+    /// # 引数
+    ///
+    /// * `position` - 機体位置（ローカル座標）
+    /// * `heading_deg` - 方位角（北から時計回り、度）
+    /// * `pitch_deg` - ピッチ角（上向き正、度）
+    /// * `roll_deg` - ロール角（右翼下げ正、度）
+    pub fn local_attitude_to_ecef_euler(
+        &self,
+        position: &Position3D,
+        heading_deg: f64,
+        pitch_deg: f64,
+        roll_deg: f64,
+    ) -> (f64, f64, f64) {
+        // 機体位置での局所NED基底（基準点との差は小さいため位置の測地座標で計算）
+        let geodetic = self.local_to_geodetic(position);
+        let lat = math_utils::deg_to_rad(geodetic.latitude_deg);
+        let lon = math_utils::deg_to_rad(geodetic.longitude_deg);
+        let (slat, clat, slon, clon) = (lat.sin(), lat.cos(), lon.sin(), lon.cos());
+        let north = [-slat * clon, -slat * slon, clat];
+        let east = [-slon, clon, 0.0];
+        let down = [-clat * clon, -clat * slon, -slat];
+
+        let psi = math_utils::deg_to_rad(heading_deg);
+        let theta = math_utils::deg_to_rad(pitch_deg);
+        let phi = math_utils::deg_to_rad(roll_deg);
+        let (sps, cps, sth, cth, sph, cph) = (psi.sin(), psi.cos(), theta.sin(), theta.cos(), phi.sin(), phi.cos());
+
+        // 機体軸（NED成分）
+        let body_x = [cth * cps, cth * sps, -sth];
+        let body_y = [-cph * sps + sph * sth * cps, cph * cps + sph * sth * sps, sph * cth];
+        let body_z = [sph * sps + cph * sth * cps, -sph * cps + cph * sth * sps, cph * cth];
+
+        let to_ecef = |v: [f64; 3]| -> [f64; 3] {
+            [
+                v[0] * north[0] + v[1] * east[0] + v[2] * down[0],
+                v[0] * north[1] + v[1] * east[1] + v[2] * down[1],
+                v[0] * north[2] + v[1] * east[2] + v[2] * down[2],
+            ]
+        };
+        let x_ecef = to_ecef(body_x);
+        let y_ecef = to_ecef(body_y);
+        let z_ecef = to_ecef(body_z);
+
+        let ecef_theta = (-x_ecef[2]).clamp(-1.0, 1.0).asin();
+        let ecef_psi = x_ecef[1].atan2(x_ecef[0]);
+        let ecef_phi = y_ecef[2].atan2(z_ecef[2]);
+
+        (ecef_psi, ecef_theta, ecef_phi)
+    }
+}
+
+/// シミュレーション規約のヨー角（+X基準・反時計回り）を方位角（北基準・時計回り）に変換
+///
+/// # 引数
+///
+/// * `yaw_deg` - +X軸を0度とし反時計回りを正とする角度（度）
+///
+/// # 戻り値
+///
+/// 北を0度とし時計回りを正とする方位角（0〜360度）
+pub fn yaw_to_heading(yaw_deg: f64) -> f64 {
+    (90.0 - yaw_deg).rem_euclid(360.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_geodetic_ecef_round_trip() {
+        let original = GeodeticPosition::new(35.681, 139.767, 40.0);
+        let restored = original.to_ecef().to_geodetic();
+        assert!((original.latitude_deg - restored.latitude_deg).abs() < 1e-9);
+        assert!((original.longitude_deg - restored.longitude_deg).abs() < 1e-9);
+        assert!((original.altitude_m - restored.altitude_m).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_enu_axes() {
+        let reference = GeodeticReference::new(GeodeticPosition::new(0.0, 0.0, 0.0));
+        let north = reference.enu_to_geodetic(&Position3D::new(0.0, 1000.0, 0.0));
+        let east = reference.enu_to_geodetic(&Position3D::new(1000.0, 0.0, 0.0));
+        assert!(north.latitude_deg > 0.0 && north.longitude_deg.abs() < 1e-9);
+        assert!(east.longitude_deg > 0.0 && east.latitude_deg.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_local_to_geodetic_keeps_altitude() {
+        let reference = GeodeticReference::new(GeodeticPosition::new(35.0, 135.0, 100.0));
+        let far = reference.local_to_geodetic(&Position3D::new(400_000.0, -400_000.0, 3000.0));
+        assert!((far.altitude_m - 3100.0).abs() < 1e-6);
+        assert!(far.longitude_deg > 135.0 && far.latitude_deg < 35.0);
+    }
+
+    #[test]
+    fn test_geodetic_to_local_round_trip() {
+        let reference = GeodeticReference::new(GeodeticPosition::new(35.0, 135.0, 100.0));
+        let local = Position3D { x: 350_000.0, y: -420_000.0, z: -50.0 };
+        let restored = reference.geodetic_to_local(&reference.local_to_geodetic(&local));
+        assert!((restored.x - local.x).abs() < 1e-3);
+        assert!((restored.y - local.y).abs() < 1e-3);
+        assert!((restored.z - local.z).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_parse_geodetic_position() {
+        let parsed: GeodeticPosition = "35.5,139.25".parse().unwrap();
+        assert_eq!(parsed, GeodeticPosition::new(35.5, 139.25, 0.0));
+        assert!("95.0,10.0".parse::<GeodeticPosition>().is_err());
+        assert!("abc".parse::<GeodeticPosition>().is_err());
+    }
+
+    #[test]
+    fn test_yaw_to_heading() {
+        assert_eq!(yaw_to_heading(0.0), 90.0);
+        assert_eq!(yaw_to_heading(90.0), 0.0);
+        assert_eq!(yaw_to_heading(180.0), 270.0);
+    }
+}
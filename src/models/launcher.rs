@@ -3,6 +3,7 @@ use crate::models::{
     traits::{IAgent, IPlatform},
     common::{Position3D, AgentStatus},
     missile::Missile,
+    lethality::Lethality,
 };
 
 /// 発射記録
@@ -57,6 +58,10 @@ pub struct Launcher {
     pub missile_max_turn_rate: f64,
     /// ミサイルの迎撃判定距離（m）
     pub missile_intercept_radius: f64,
+    /// 搭載ミサイル種別のID（Noneの場合はデフォルト）
+    pub missile_type: Option<String>,
+    /// 搭載ミサイルの迎撃効果（Noneの場合は命中ごとに耐久値を1減少）
+    pub missile_lethality: Option<Lethality>,
 }
 
 impl Launcher {
@@ -87,6 +92,8 @@ impl Launcher {
             missile_max_accel: 0.0,             // initializeで設定
             missile_max_turn_rate: 0.0,         // initializeで設定
             missile_intercept_radius: 0.0,      // initializeで設定
+            missile_type: None,                 // initializeで設定
+            missile_lethality: None,            // initializeで設定
         }
     }
 
@@ -113,11 +120,12 @@ impl Launcher {
         let missile_id = format!("{}_M{:03}", self.id, self.missile_counter);
 
         // ミサイル作成
-        let missile = Missile::new(
+        let mut missile = Missile::new(
             missile_id.clone(),
             self.position,
             target_id.clone(),
         );
+        missile.lethality = self.missile_lethality;
 
         // ランチャー状態更新
         self.current_missiles -= 1;
@@ -221,11 +229,12 @@ impl Launcher {
         self.missile_counter += 1;
 
         let missile_id = format!("{}_{:03}", self.id, self.missile_counter);
-        let missile = Missile::new(
+        let mut missile = Missile::new(
             missile_id,
             self.position,
             target_id.to_string(),
         );
+        missile.lethality = self.missile_lethality;
 
         // 発射記録を追加
         let launch_record = LaunchRecord {
@@ -375,6 +384,17 @@ impl IAgent for Launcher {
                 } else {
                     self.cooldown_remaining = launcher_config.cooldown_s;
                 }
+                
+                // 搭載ミサイル種別と迎撃効果の設定
+                self.missile_type = launcher_config.missile_type.clone();
+                self.missile_lethality = scenario_config.missile_defaults
+                    .lethality_for(launcher_config.missile_type.as_deref())
+                    .map(|lethality| Lethality {
+                        pk: lethality.pk,
+                        pk_at_radius: lethality.pk_at_radius,
+                        max_closing_speed: lethality.max_closing_speed_mps,
+                        damage_probability: lethality.damage_probability,
+                    });
                 break;
             }
         }
@@ -0,0 +1,122 @@
+//! # Lethality モジュール
+//!
+//! ミサイル命中時の迎撃効果（単発撃墜確率）を定義します。
+//!
+//! 命中（迎撃判定距離内への到達）ごとにエンジンの乱数で結果を判定し、
+//! 撃墜・損傷（耐久値1減少）・効果なしのいずれかとします。
+//! 撃墜確率は基準値に対して、命中時のmiss distanceと接近速度で補正できます。
+//!
+//! ## 撃墜確率の補正
+//!
+//! - **miss distance**: `pk_at_radius` 指定時、miss distance 0で `pk`、迎撃判定距離で `pk_at_radius` となるよう線形補間
+//! - **接近速度**: `max_closing_speed` 指定時、それを超える接近速度では `max_closing_speed / 接近速度` 倍
+
+use rand::Rng;
+
+/// 命中時の迎撃結果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterceptOutcome {
+    /// 撃墜（耐久値に関わらず破壊）
+    Kill,
+    /// 損傷（耐久値を1減少）
+    Damage,
+    /// 効果なし
+    NoEffect,
+}
+
+/// ミサイル種別ごとの迎撃効果モデル
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lethality {
+    /// 基準の単発撃墜確率
+    pub pk: f64,
+    /// 迎撃判定距離ちょうどでの撃墜確率（未指定時はmiss distanceによらない）
+    pub pk_at_radius: Option<f64>,
+    /// 撃墜確率が低下し始める接近速度（m/s、未指定時は接近速度によらない）
+    pub max_closing_speed: Option<f64>,
+    /// 撃墜に至らなかった場合に損傷となる確率
+    pub damage_probability: f64,
+}
+
+impl Lethality {
+    /// 命中条件に応じた撃墜確率
+    ///
+    /// # 引数
+    ///
+    /// * `miss_distance` - 命中時のミサイルとターゲットの距離（メートル）
+    /// * `intercept_radius` - ミサイルの迎撃判定距離（メートル）
+    /// * `closing_speed` - 命中時の相対速度の大きさ（m/s）
+    pub fn kill_probability(&self, miss_distance: f64, intercept_radius: f64, closing_speed: f64) -> f64 {
+        let mut pk = self.pk;
+        if let Some(pk_at_radius) = self.pk_at_radius
+            && intercept_radius > 0.0
+        {
+            let fraction = (miss_distance / intercept_radius).clamp(0.0, 1.0);
+            pk += (pk_at_radius - pk) * fraction;
+        }
+        if let Some(max_closing_speed) = self.max_closing_speed
+            && closing_speed > max_closing_speed
+        {
+            pk *= max_closing_speed / closing_speed;
+        }
+        pk.clamp(0.0, 1.0)
+    }
+
+    /// 命中時の迎撃結果を判定
+    ///
+    /// # 引数
+    ///
+    /// * `rng` - 乱数生成器
+    /// * `miss_distance` - 命中時のミサイルとターゲットの距離（メートル）
+    /// * `intercept_radius` - ミサイルの迎撃判定距離（メートル）
+    /// * `closing_speed` - 命中時の相対速度の大きさ（m/s）
+    ///
+    /// # 戻り値
+    ///
+    /// 迎撃結果と判定に使用した撃墜確率
+    pub fn resolve<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        miss_distance: f64,
+        intercept_radius: f64,
+        closing_speed: f64,
+    ) -> (InterceptOutcome, f64) {
+        let pk = self.kill_probability(miss_distance, intercept_radius, closing_speed);
+        let roll: f64 = rng.gen_range(0.0..1.0);
+        let outcome = if roll < pk {
+            InterceptOutcome::Kill
+        } else if roll < pk + (1.0 - pk) * self.damage_probability {
+            InterceptOutcome::Damage
+        } else {
+            InterceptOutcome::NoEffect
+        };
+        (outcome, pk)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_kill_probability_falloff_and_outcome_rates() {
+        let lethality = Lethality {
+            pk: 0.9,
+            pk_at_radius: Some(0.5),
+            max_closing_speed: Some(1000.0),
+            damage_probability: 0.5,
+        };
+        assert!((lethality.kill_probability(0.0, 50.0, 800.0) - 0.9).abs() < 1e-12);
+        assert!((lethality.kill_probability(25.0, 50.0, 800.0) - 0.7).abs() < 1e-12);
+        assert!((lethality.kill_probability(0.0, 50.0, 2000.0) - 0.45).abs() < 1e-12);
+
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+        let outcomes: Vec<InterceptOutcome> = (0..10_000)
+            .map(|_| lethality.resolve(&mut rng, 50.0, 50.0, 500.0).0)
+            .collect();
+        let rate = |outcome| outcomes.iter().filter(|o| **o == outcome).count() as f64 / outcomes.len() as f64;
+        assert!((rate(InterceptOutcome::Kill) - 0.5).abs() < 0.02);
+        assert!((rate(InterceptOutcome::Damage) - 0.25).abs() < 0.02);
+    }
+}
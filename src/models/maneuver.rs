@@ -0,0 +1,188 @@
+//! # Maneuver モジュール
+//!
+//! ミサイル終盤に敵ターゲットが行う回避機動を定義します。
+//!
+//! ## 機動の種類
+//!
+//! - **ジンク（Jink）**: 一定間隔ごとにランダムな方向へ横加速度をかける不規則機動
+//! - **バレルロール（BarrelRoll）**: 横加速度の向きを速度軸周りに一定周期で回転させる螺旋機動
+//! - **ブレークターン（BreakTurn）**: 脅威ミサイルの方向へ最大荷重で水平旋回
+//!
+//! いずれの機動も速度の大きさを保ったまま、速度ベクトルに垂直な加速度
+//! （最大荷重 `max_g` × 重力加速度）を与えます。機動は継続時間経過後に終了し、
+//! ターゲットは経路点または目的地への直進に復帰します。
+
+use rand::Rng;
+
+use crate::models::common::{Position3D, Velocity3D};
+
+/// 重力加速度（m/s²）
+pub const GRAVITY_MPS2: f64 = 9.80665;
+
+/// 回避機動の種類
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ManeuverType {
+    /// ランダム方向への不規則機動
+    Jink,
+    /// 速度軸周りの螺旋機動
+    BarrelRoll,
+    /// 脅威方向への最大荷重水平旋回
+    BreakTurn,
+}
+
+/// 回避機動プロファイル
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ManeuverProfile {
+    /// 機動の種類
+    pub kind: ManeuverType,
+    /// 最大荷重（G）
+    pub max_g: f64,
+    /// 機動の継続時間（秒）
+    pub duration_s: f64,
+    /// ジンクの方向変更間隔 / バレルロールの1回転周期（秒）
+    pub period_s: f64,
+    /// 交戦中のミサイルがこの距離以内に入ったら開始（省略時は終盤フェーズ移行時のみ）
+    pub trigger_range_m: Option<f64>,
+}
+
+/// 実行中の回避機動
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ActiveManeuver {
+    /// 機動開始からの経過時間（秒）
+    pub elapsed_s: f64,
+    /// 横加速度の向き（速度軸周りの角度、ラジアン。0で水平左方向）
+    roll_angle: f64,
+    /// ジンクで次に方向を変える経過時間（秒）
+    next_jink_s: f64,
+}
+
+impl ManeuverProfile {
+    /// 機動を開始
+    ///
+    /// # 引数
+    ///
+    /// * `position` - ターゲット位置
+    /// * `velocity` - ターゲット速度
+    /// * `threat_position` - 脅威ミサイルの位置
+    /// * `rng` - 乱数生成器（ジンク・バレルロールの初期方向に使用）
+    pub fn start<R: Rng + ?Sized>(
+        &self,
+        position: &Position3D,
+        velocity: &Velocity3D,
+        threat_position: &Position3D,
+        rng: &mut R,
+    ) -> ActiveManeuver {
+        let roll_angle = match self.kind {
+            // 脅威が左側なら左旋回（0）、右側なら右旋回（π）
+            ManeuverType::BreakTurn => {
+                let cross = velocity.x * (threat_position.y - position.y) - velocity.y * (threat_position.x - position.x);
+                if cross >= 0.0 { 0.0 } else { std::f64::consts::PI }
+            }
+            ManeuverType::Jink | ManeuverType::BarrelRoll => rng.gen_range(0.0..std::f64::consts::TAU),
+        };
+        ActiveManeuver { elapsed_s: 0.0, roll_angle, next_jink_s: self.period_s }
+    }
+
+    /// 機動を1ステップ進め、新しい速度ベクトルを返す
+    ///
+    /// # 引数
+    ///
+    /// * `state` - 実行中の機動状態
+    /// * `velocity` - 現在の速度
+    /// * `dt` - 時間刻み（秒）
+    /// * `rng` - 乱数生成器（ジンクの方向変更に使用）
+    ///
+    /// # 戻り値
+    ///
+    /// 機動後の速度（速度の大きさは保存）
+    pub fn apply<R: Rng + ?Sized>(
+        &self,
+        state: &mut ActiveManeuver,
+        velocity: &Velocity3D,
+        dt: f64,
+        rng: &mut R,
+    ) -> Velocity3D {
+        state.elapsed_s += dt;
+        match self.kind {
+            ManeuverType::Jink => {
+                if state.elapsed_s >= state.next_jink_s {
+                    state.roll_angle = rng.gen_range(0.0..std::f64::consts::TAU);
+                    state.next_jink_s += self.period_s;
+                }
+            }
+            ManeuverType::BarrelRoll => {
+                if self.period_s > 0.0 {
+                    state.roll_angle += std::f64::consts::TAU * dt / self.period_s;
+                }
+            }
+            ManeuverType::BreakTurn => {}
+        }
+
+        let speed = velocity.magnitude();
+        if speed <= 0.0 {
+            return *velocity;
+        }
+        let forward = [velocity.x / speed, velocity.y / speed, velocity.z / speed];
+
+        // 速度に垂直な基底（水平左方向と、それに直交する上方向）
+        let horizontal = (forward[0].powi(2) + forward[1].powi(2)).sqrt();
+        let left = if horizontal > 1e-9 {
+            [-forward[1] / horizontal, forward[0] / horizontal, 0.0]
+        } else {
+            [0.0, 1.0, 0.0]
+        };
+        let up = [
+            forward[1] * left[2] - forward[2] * left[1],
+            forward[2] * left[0] - forward[0] * left[2],
+            forward[0] * left[1] - forward[1] * left[0],
+        ];
+
+        let accel = self.max_g * GRAVITY_MPS2;
+        let (sin, cos) = state.roll_angle.sin_cos();
+        let turned = Velocity3D::new(
+            velocity.x + accel * (cos * left[0] + sin * up[0]) * dt,
+            velocity.y + accel * (cos * left[1] + sin * up[1]) * dt,
+            velocity.z + accel * (cos * left[2] + sin * up[2]) * dt,
+        );
+        let turned_speed = turned.magnitude();
+        Velocity3D::new(
+            turned.x / turned_speed * speed,
+            turned.y / turned_speed * speed,
+            turned.z / turned_speed * speed,
+        )
+    }
+
+    /// 機動が終了したか
+    pub fn is_finished(&self, state: &ActiveManeuver) -> bool {
+        state.elapsed_s >= self.duration_s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_break_turn_keeps_speed_and_turns_toward_threat() {
+        let profile = ManeuverProfile {
+            kind: ManeuverType::BreakTurn,
+            max_g: 6.0,
+            duration_s: 5.0,
+            period_s: 1.0,
+            trigger_range_m: None,
+        };
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let position = Position3D::new(0.0, 0.0, 3000.0);
+        let mut velocity = Velocity3D::new(200.0, 0.0, 0.0);
+        // 脅威は右側（-Y）
+        let mut state = profile.start(&position, &velocity, &Position3D::new(1000.0, -2000.0, 0.0), &mut rng);
+        for _ in 0..10 {
+            velocity = profile.apply(&mut state, &velocity, 0.1, &mut rng);
+        }
+        assert!((velocity.magnitude() - 200.0).abs() < 1e-9);
+        assert!(velocity.y < 0.0 && velocity.z.abs() < 1e-9);
+        assert!(!profile.is_finished(&state));
+    }
+}
@@ -1,6 +1,7 @@
 use crate::models::{
     traits::{IAgent, IMovable, IMissile, ICollision},
     common::{Position3D, Velocity3D, Acceleration3D, AgentStatus, math_utils},
+    lethality::Lethality,
 };
 use tracing::{info, warn, error, debug, trace};
 
@@ -55,6 +56,8 @@ pub struct Missile {
     pub max_turn_rate: f64,
     /// 迎撃判定距離（m）
     pub intercept_radius: f64,
+    /// 上昇限度（m）
+    pub max_altitude: f64,
     
     /// 比例航法定数（通常3-4）
     pub guidance_n: f64,
@@ -81,6 +84,15 @@ pub struct Missile {
     pub total_distance: f64,
     /// 終了理由
     pub end_reason: Option<MissileEndReason>,
+    
+    /// ターゲット速度の推定値（前回位置との差分、m/s）
+    pub target_velocity: Velocity3D,
+    /// 前回更新時のターゲット位置
+    pub last_target_position: Option<Position3D>,
+    /// 直近ステップ開始時の（ミサイル位置, ターゲット位置）。区間内最接近距離の計算に使用
+    pub segment_start: Option<(Position3D, Position3D)>,
+    /// 迎撃効果（Noneの場合は命中ごとに耐久値を1減少）
+    pub lethality: Option<Lethality>,
 }
 
 /// 3次元姿勢
@@ -175,6 +187,7 @@ impl Missile {
             max_accel: 0.0,                         // initializeで設定
             max_turn_rate: 0.0,                     // initializeで設定
             intercept_radius: 0.0,                  // initializeで設定
+            max_altitude: 5000.0,                   // initializeで設定
             guidance_n: 0.0,                        // initializeで設定
             guidance_phase: GuidancePhase::Boost,
             endgame_threshold: 0.0,                 // initializeで設定
@@ -186,13 +199,18 @@ impl Missile {
             flight_time: 0.0,
             total_distance: 0.0,
             end_reason: None,
+            target_velocity: Velocity3D::new(0.0, 0.0, 0.0),
+            last_target_position: None,
+            segment_start: None,
+            lethality: None,
         }
     }
 
     /// True 3D比例航法による誘導計算
     /// 
     /// 真の3次元比例航法を使用して、ターゲットへの誘導加速度を計算します。
-    /// 比例航法定数NとLOS（Line-of-Sight）角速度を使用して計算します。
+    /// LOS角速度ベクトル ω = (r × v_rel) / |r|² を求め、
+    /// 指令加速度 a = N・Vc・(ω × r̂) を算出します。
     /// 
     /// # 引数
     /// 
@@ -202,44 +220,48 @@ impl Missile {
     /// 
     /// 誘導に必要な加速度ベクトル
     pub fn calculate_proportional_navigation(&mut self, target_position: Position3D) -> Acceleration3D {
-        let relative_position = target_position - self.position;
-        let relative_distance = relative_position.magnitude();
+        // 相対位置（Position3Dは高度をクランプするため成分で計算）
+        let rx = target_position.x - self.position.x;
+        let ry = target_position.y - self.position.y;
+        let rz = target_position.z - self.position.z;
+        let relative_distance_sq = rx * rx + ry * ry + rz * rz;
+        let relative_distance = relative_distance_sq.sqrt();
         
         if relative_distance < 1e-6 {
             return Acceleration3D::new(0.0, 0.0, 0.0);
         }
 
-        // 相対速度（ターゲットの速度は0と仮定、実際はターゲット速度も考慮が必要）
-        let relative_velocity = self.velocity;
-        
-        // Line-of-Sight (LOS) 方向単位ベクトル
-        let los_unit = Position3D::new(
-            relative_position.x / relative_distance,
-            relative_position.y / relative_distance,
-            relative_position.z / relative_distance,
-        );
+        // 相対速度（推定ターゲット速度 - ミサイル速度）
+        let vx = self.target_velocity.x - self.velocity.x;
+        let vy = self.target_velocity.y - self.velocity.y;
+        let vz = self.target_velocity.z - self.velocity.z;
         
         // 接近速度
-        let closing_velocity = -(
-            relative_velocity.x * los_unit.x +
-            relative_velocity.y * los_unit.y +
-            relative_velocity.z * los_unit.z
-        );
+        let closing_velocity = -(rx * vx + ry * vy + rz * vz) / relative_distance;
+        
+        // ミサイル速度とLOSのなす角の余弦（発射直後の垂直上昇時などは大きく外れる）
+        let speed = self.velocity.magnitude();
+        let heading_cos = if speed > 1e-6 {
+            (rx * self.velocity.x + ry * self.velocity.y + rz * self.velocity.z) / (relative_distance * speed)
+        } else {
+            0.0
+        };
         
-        if closing_velocity <= 0.0 {
-            // 離れている場合は直接追尾
+        if closing_velocity <= 0.0 || heading_cos < 0.5 {
+            // 離れている場合・LOSとの角度が60度を超える場合は直接追尾で指向させる
             return self.calculate_direct_pursuit(target_position);
         }
         
-        // LOS角速度の近似計算
-        let los_rate_x = (relative_velocity.y * los_unit.z - relative_velocity.z * los_unit.y) / relative_distance;
-        let los_rate_y = (relative_velocity.z * los_unit.x - relative_velocity.x * los_unit.z) / relative_distance;
-        let los_rate_z = (relative_velocity.x * los_unit.y - relative_velocity.y * los_unit.x) / relative_distance;
+        // LOS角速度ベクトル ω = (r × v_rel) / |r|²
+        let los_rate_x = (ry * vz - rz * vy) / relative_distance_sq;
+        let los_rate_y = (rz * vx - rx * vz) / relative_distance_sq;
+        let los_rate_z = (rx * vy - ry * vx) / relative_distance_sq;
         
-        // 比例航法による必要加速度
-        let accel_x = self.guidance_n * closing_velocity * los_rate_x;
-        let accel_y = self.guidance_n * closing_velocity * los_rate_y;
-        let accel_z = self.guidance_n * closing_velocity * los_rate_z;
+        // 比例航法による必要加速度 a = N・Vc・(ω × r̂)
+        let gain = self.guidance_n * closing_velocity / relative_distance;
+        let accel_x = gain * (los_rate_y * rz - los_rate_z * ry);
+        let accel_y = gain * (los_rate_z * rx - los_rate_x * rz);
+        let accel_z = gain * (los_rate_x * ry - los_rate_y * rx);
         
         Acceleration3D::new(accel_x, accel_y, accel_z)
     }
@@ -257,8 +279,10 @@ impl Missile {
     /// 
     /// ターゲット方向への最大加速度ベクトル
     pub fn calculate_direct_pursuit(&self, target_position: Position3D) -> Acceleration3D {
-        let direction = target_position - self.position;
-        let distance = direction.magnitude();
+        let dx = target_position.x - self.position.x;
+        let dy = target_position.y - self.position.y;
+        let dz = target_position.z - self.position.z;
+        let distance = (dx * dx + dy * dy + dz * dz).sqrt();
         
         if distance < 1e-6 {
             return Acceleration3D::new(0.0, 0.0, 0.0);
@@ -267,9 +291,9 @@ impl Missile {
         // ターゲット方向への最大加速度
         let accel_magnitude = self.max_accel;
         Acceleration3D::new(
-            (direction.x / distance) * accel_magnitude,
-            (direction.y / distance) * accel_magnitude,
-            (direction.z / distance) * accel_magnitude,
+            (dx / distance) * accel_magnitude,
+            (dy / distance) * accel_magnitude,
+            (dz / distance) * accel_magnitude,
         )
     }
 
@@ -438,6 +462,17 @@ impl Missile {
     /// * `dt` - 時間ステップ（秒）
     /// * `target_position` - ターゲットの現在位置
     pub fn update_kinematics(&mut self, dt: f64, target_position: Position3D) {
+        // ターゲット速度の推定（前回位置との差分）
+        let previous_target_position = self.last_target_position.replace(target_position);
+        if let Some(previous) = previous_target_position.filter(|_| dt > 0.0) {
+            self.target_velocity = Velocity3D::new(
+                (target_position.x - previous.x) / dt,
+                (target_position.y - previous.y) / dt,
+                (target_position.z - previous.z) / dt,
+            );
+        }
+        self.segment_start = previous_target_position.map(|previous| (self.position, previous));
+        
         // 1. 誘導計算
         self.acceleration = match self.guidance_phase {
             GuidancePhase::Boost => {
@@ -452,6 +487,18 @@ impl Missile {
         // 2. 加速度ベクトル飽和
         self.acceleration = self.acceleration.clamp_magnitude(self.max_accel);
         
+        // 推力による増速（誘導加速度の残り枠を速度方向に割り当て、最大速度まで加速）
+        let speed = self.velocity.magnitude();
+        if speed > 1e-6 && speed < self.max_speed {
+            let remaining = (self.max_accel.powi(2) - self.acceleration.magnitude().powi(2)).max(0.0).sqrt();
+            let thrust = remaining.min((self.max_speed - speed) / dt.max(1e-6));
+            self.acceleration = self.acceleration + Acceleration3D::new(
+                self.velocity.x / speed * thrust,
+                self.velocity.y / speed * thrust,
+                self.velocity.z / speed * thrust,
+            );
+        }
+        
         // 3. 速度積分
         self.velocity = self.velocity + self.acceleration * dt;
         
@@ -460,14 +507,16 @@ impl Missile {
         
         // 5. 位置更新
         let previous_position = self.position;
-        self.position = self.position + Position3D::new(
-            self.velocity.x * dt,
-            self.velocity.y * dt,
-            self.velocity.z * dt,
-        );
-        
-        // 高度制限適用
-        self.position.z = self.position.z.clamp(0.0, 5000.0);
+        // 変位はPosition3D::newを通すと負の高度成分がクランプされるため成分ごとに加算
+        self.position.x += self.velocity.x * dt;
+        self.position.y += self.velocity.y * dt;
+        self.position.z += self.velocity.z * dt;
+        
+        // 高度制限適用（地表と上昇限度に達した場合は鉛直速度を打ち消す）
+        if self.position.z <= 0.0 && self.velocity.z < 0.0 || self.position.z >= self.max_altitude && self.velocity.z > 0.0 {
+            self.velocity.z = 0.0;
+        }
+        self.position.z = self.position.z.clamp(0.0, self.max_altitude);
         
         // 6. 姿勢更新
         self.update_attitude(dt);
@@ -486,8 +535,8 @@ impl Missile {
     /// 
     /// * `target_position` - ターゲットの現在位置
     pub fn perform_checks(&mut self, target_position: Position3D) {
-        // 領域外チェック
-        if !self.position.is_in_simulation_bounds() {
+        // 領域外チェック（高度は地表と上昇限度の間にクランプ済みのため水平位置で判定）
+        if !Position3D::new(self.position.x, self.position.y, 0.0).is_in_simulation_bounds() {
             self.status = AgentStatus::SelfDestruct;
             self.end_reason = Some(MissileEndReason::OutOfBounds);
             
@@ -502,7 +551,7 @@ impl Missile {
                 total_distance = self.total_distance,
                 simulation_bounds_x = "±1,000,000m",
                 simulation_bounds_y = "±1,000,000m",
-                simulation_bounds_z = "0-5,000m",
+                max_altitude = self.max_altitude,
                 "MISSILE_OUT_OF_BOUNDS: ミサイルがシミュレーション領域外に出ました"
             );
             
@@ -537,6 +586,30 @@ impl Missile {
             );
         }
     }
+
+    /// ターゲット消失時の処理
+    /// 
+    /// 誘導対象が撃破・突破等で消滅した場合に即時自爆します。
+    pub fn on_target_lost(&mut self) {
+        if self.status != AgentStatus::Active {
+            return;
+        }
+        
+        self.status = AgentStatus::SelfDestruct;
+        self.end_reason = Some(MissileEndReason::TargetLost);
+        
+        // ターゲット消失ログ
+        info!(
+            missile_id = %self.id,
+            target_id = %self.target_id,
+            self_destruct_position_x = self.position.x,
+            self_destruct_position_y = self.position.y,
+            self_destruct_position_z = self.position.z,
+            flight_time = self.flight_time,
+            total_distance = self.total_distance,
+            "MISSILE_TARGET_LOST: 誘導対象が消滅したためミサイルが自爆しました"
+        );
+    }
 }
 
 impl IAgent for Missile {
@@ -554,6 +627,7 @@ impl IAgent for Missile {
         self.max_accel = missile_kinematics.max_accel_mps2;
         self.max_turn_rate = missile_kinematics.max_turn_rate_deg_s;
         self.intercept_radius = missile_kinematics.intercept_radius_m;
+        self.max_altitude = missile_kinematics.max_altitude_m;
         
         // 誘導設定の適用
         let guidance_config = &scenario_config.policy.missile_guidance;
@@ -666,16 +740,144 @@ impl IMissile for Missile {
 
 impl ICollision for Missile {
     fn check_collision(&self, target_position: Position3D) -> bool {
-        let distance = self.position.distance_3d(&target_position);
-        distance <= self.intercept_radius
+        self.calculate_miss_distance(target_position) <= self.intercept_radius
     }
 
     fn calculate_miss_distance(&self, target_position: Position3D) -> f64 {
-        self.position.distance_3d(&target_position)
+        // 直近ステップ区間内の最接近距離（Δt間のすり抜けを防ぐ）
+        let Some((missile_start, target_start)) = self.segment_start else {
+            return self.position.distance_3d(&target_position);
+        };
+        
+        let r0 = [
+            target_start.x - missile_start.x,
+            target_start.y - missile_start.y,
+            target_start.z - missile_start.z,
+        ];
+        let r1 = [
+            target_position.x - self.position.x,
+            target_position.y - self.position.y,
+            target_position.z - self.position.z,
+        ];
+        let d = [r1[0] - r0[0], r1[1] - r0[1], r1[2] - r0[2]];
+        let d_sq = d[0] * d[0] + d[1] * d[1] + d[2] * d[2];
+        let t = if d_sq > 1e-12 {
+            (-(r0[0] * d[0] + r0[1] * d[1] + r0[2] * d[2]) / d_sq).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        
+        ((r0[0] + t * d[0]).powi(2) + (r0[1] + t * d[1]).powi(2) + (r0[2] + t * d[2]).powi(2)).sqrt()
     }
 
     fn is_endgame_phase(&self, target_position: Position3D) -> bool {
         let distance = self.position.distance_3d(&target_position);
         distance <= self.endgame_threshold
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ballistic::{BallisticShape, BallisticTrajectory};
+
+    fn missile(position: Position3D, velocity: Velocity3D) -> Missile {
+        let mut missile = Missile::new("M001".to_string(), position, "T001".to_string());
+        missile.velocity = velocity;
+        missile.initial_speed = velocity.magnitude();
+        missile.max_speed = 1200.0;
+        missile.max_accel = 300.0;
+        missile.max_turn_rate = 40.0;
+        missile.intercept_radius = 50.0;
+        missile.guidance_n = 3.0;
+        missile.guidance_phase = GuidancePhase::Midcourse;
+        missile.endgame_threshold = 100.0;
+        missile.endgame_miss_increase_ticks = 3;
+        missile
+    }
+
+    #[test]
+    fn test_proportional_navigation_intercepts_constant_velocity_target() {
+        let dt = 0.1;
+        let mut missile = missile(Position3D::new(0.0, 0.0, 2000.0), Velocity3D::new(800.0, 0.0, 0.0));
+        // 進行方向を横切るターゲット
+        let target_velocity = Velocity3D::new(-100.0, -200.0, 0.0);
+        let mut target = Position3D::new(30_000.0, 15_000.0, 2000.0);
+
+        let mut steps = 0;
+        while missile.is_active() && steps < 1000 {
+            target.x += target_velocity.x * dt;
+            target.y += target_velocity.y * dt;
+            missile.update_kinematics(dt, target);
+            missile.perform_checks(target);
+            steps += 1;
+        }
+
+        assert_eq!(missile.end_reason, Some(MissileEndReason::Hit));
+        // 差分から推定したターゲット速度を誘導に使用
+        assert!((missile.target_velocity.x - target_velocity.x).abs() < 1e-6);
+        assert!((missile.target_velocity.y - target_velocity.y).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_fly_through_within_step_counts_as_hit() {
+        let dt = 0.1;
+        // 1ステップで100m進み、ステップ端点ではいずれも迎撃判定距離外を通過する
+        let mut missile = missile(Position3D::new(0.0, 0.0, 1000.0), Velocity3D::new(1000.0, 0.0, 0.0));
+        missile.max_speed = 1000.0;
+        let target = Position3D::new(150.0, 30.0, 1000.0);
+
+        missile.update_kinematics(dt, target);
+        missile.perform_checks(target);
+        assert!(missile.is_active());
+
+        missile.update_kinematics(dt, target);
+        assert!(missile.position.distance_3d(&target) > missile.intercept_radius);
+        missile.perform_checks(target);
+        assert_eq!(missile.end_reason, Some(MissileEndReason::Hit));
+        assert!(missile.calculate_miss_distance(target) < 35.0);
+    }
+
+    #[test]
+    fn test_proportional_navigation_intercepts_descending_ballistic_target() {
+        let dt = 0.1;
+        // 射程100km・アポジー15kmの弾道の下降区間を迎撃
+        let trajectory = BallisticTrajectory::new(
+            Position3D::new(0.0, 0.0, 0.0),
+            Position3D::new(100_000.0, 0.0, 0.0),
+            BallisticShape::Apogee(15_000.0),
+        ).unwrap();
+        let mut missile = missile(Position3D::new(90_000.0, 2000.0, 0.0), Velocity3D::new(0.0, 0.0, 350.0));
+        missile.max_altitude = 20_000.0;
+
+        let mut time = trajectory.flight_time / 2.0;
+        let mut target = trajectory.position_at(time);
+        while missile.is_active() && time < trajectory.flight_time {
+            time += dt;
+            target = trajectory.position_at(time);
+            missile.update_kinematics(dt, target);
+            missile.perform_checks(target);
+        }
+
+        assert_eq!(missile.end_reason, Some(MissileEndReason::Hit));
+        assert!(trajectory.velocity_at(time).z < 0.0);
+        // 従来の高度上限（5000m）より上で迎撃
+        assert!(target.z > 5000.0 && missile.position.z > 5000.0, "{}", target.z);
+    }
+
+    #[test]
+    fn test_target_lost_self_destructs_active_missile_only() {
+        let mut missile = missile(Position3D::new(0.0, 0.0, 1000.0), Velocity3D::new(800.0, 0.0, 0.0));
+        missile.on_target_lost();
+        assert!(!missile.is_active());
+        assert_eq!(missile.status, AgentStatus::SelfDestruct);
+        assert_eq!(missile.end_reason, Some(MissileEndReason::TargetLost));
+
+        // 同じサイクルで命中済みのミサイルは終了理由を変えない
+        let mut hit = missile.clone();
+        hit.status = AgentStatus::Destroyed;
+        hit.end_reason = Some(MissileEndReason::Hit);
+        hit.on_target_lost();
+        assert_eq!(hit.end_reason, Some(MissileEndReason::Hit));
+    }
+}
@@ -9,12 +9,21 @@
 //! ## 主要コンポーネント
 //! 
 //! - **common**: 3次元座標、速度、加速度などの基本データ型と数学ユーティリティ
+//! - **geodetic**: ローカル座標とWGS84測地座標・ECEF座標の相互変換
 //! - **traits**: 全エージェントが実装すべき共通インターフェースの定義
 //! - **target**: 敵ターゲットエージェントとグループ配置機能
+//! - **maneuver**: 敵ターゲットの回避機動（ジンク・バレルロール・ブレークターン）
+//! - **ballistic**: 弾道ミサイル型ターゲットの重力弾道
+//! - **cruise**: 巡航ミサイル型ターゲットの低高度飛行プロファイル
+//! - **defended_asset**: 敵ターゲットの攻撃目標となる防護対象（航空基地・港湾など）
 //! - **command_post**: 中央指揮所エージェントとターゲット優先度管理
 //! - **sensor**: ターゲット検知センサーエージェントとネットワーク機能
 //! - **launcher**: ミサイル発射ランチャーエージェントと統計機能
 //! - **missile**: 誘導ミサイルエージェントと3次元誘導アルゴリズム
+//! - **lethality**: ミサイル種別ごとの迎撃効果（単発撃墜確率）
+//! - **tracking**: センサーの雑音付き観測とカルマンフィルタによる航跡推定
+//! - **detection**: センサーの探知確率（Pd）モデルとM-of-N航跡確立
+//! - **fusion**: 複数センサーの観測を相関・融合したシステム航跡
 //! 
 //! ## エージェントアーキテクチャ
 //! 
@@ -33,21 +42,41 @@
 /// 基本的なデータ型と数学ユーティリティ
 pub mod common;
 
+/// ローカル座標と測地座標の変換
+pub mod geodetic;
+
 // エージェントの基本インターフェース（trait）定義
 pub mod traits;
 
 // 各エージェントモデルの実装
 pub mod target;
+pub mod maneuver;
+pub mod ballistic;
+pub mod cruise;
+pub mod defended_asset;
 pub mod command_post;
 pub mod sensor;
 pub mod launcher;
 pub mod missile;
+pub mod lethality;
+pub mod tracking;
+pub mod detection;
+pub mod fusion;
 
 // 便利な re-export
 pub use common::*;
+pub use geodetic::{GeodeticPosition, GeodeticReference};
 pub use traits::*;
-pub use target::{Target, TargetGroup};
+pub use target::{AltitudeProfile, Formation, SpawnJitter, Target, TargetGroup, Waypoint};
+pub use maneuver::{ManeuverProfile, ManeuverType};
+pub use ballistic::BallisticShape;
+pub use cruise::{CruiseProfile, TerminalManeuver};
+pub use defended_asset::DefendedAsset;
 pub use command_post::{CommandPost, TargetPriority};
-pub use sensor::{Sensor, SensorNetwork, DetectionEvent, DetectionEventType, DetectionStats};
+pub use sensor::{Sensor, SensorNetwork, SensorReport, DetectionEvent, DetectionEventType, DetectionStats};
 pub use launcher::{Launcher, LauncherBattery, LaunchRecord, LaunchStats, BatteryStats};
-pub use missile::{Missile, GuidancePhase, MissileEndReason, Attitude3D};
\ No newline at end of file
+pub use missile::{Missile, GuidancePhase, MissileEndReason, Attitude3D};
+pub use lethality::InterceptOutcome;
+pub use tracking::TrackErrorStats;
+pub use detection::DetectionProbability;
+pub use fusion::SystemTracker;
\ No newline at end of file
@@ -1,14 +1,50 @@
 use std::collections::{HashSet, HashMap};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
 use crate::models::{
     traits::{IAgent, ISensor},
-    common::{Position3D, AgentStatus},
+    common::{Position3D, AgentStatus, math_utils},
     target::Target,
+    tracking::{Measurement, MeasurementNoise, DEFAULT_PROCESS_NOISE_MPS2},
+    detection::{DetectionProbability, LookHistory},
+    fusion::{SystemTracker, DEFAULT_COAST_TIME_S, DEFAULT_GATE},
 };
 
+/// 探知範囲（`detection_range`）の基準となるレーダー反射断面積の既定値（平方メートル）
+pub const REFERENCE_RCS_M2: f64 = 1.0;
+
+/// レーダー水平線の計算に用いる等価地球半径（4/3地球半径、メートル）
+pub const EFFECTIVE_EARTH_RADIUS_M: f64 = 6_371_000.0 * 4.0 / 3.0;
+
+/// 2つの高度間のレーダー水平線距離
+/// 
+/// 等価地球半径モデルで、それぞれの高度から水平線までの距離の和を返します。
+/// 
+/// # 引数
+/// 
+/// * `altitude_a` - 一方の高度（メートル）
+/// * `altitude_b` - もう一方の高度（メートル）
+/// 
+/// # 戻り値
+/// 
+/// 見通しが成立する最大距離（メートル）
+pub fn radar_horizon_range(altitude_a: f64, altitude_b: f64) -> f64 {
+    let horizon = |altitude: f64| (2.0 * EFFECTIVE_EARTH_RADIUS_M * altitude.max(0.0)).sqrt();
+    horizon(altitude_a) + horizon(altitude_b)
+}
+
 /// センサーエージェント
 /// 
 /// 敵ターゲットを検知し、指揮所に情報を提供するセンサーシステムです。
 /// 球形の検知範囲を持ち、ターゲットの初回検知、追跡、ロストを管理します。
+/// 探知範囲は基準RCS（`reference_rcs`）に対する値で、ターゲットのRCSに応じてレーダー方程式
+/// （探知距離はRCSの4乗根に比例）でスケーリングされます。
+/// 視野（`field_of_view`）を持つセンサーは方位・仰角の範囲内のみ、回転走査（`scan`）を行う
+/// センサーはビームが掃引した方位のターゲットのみを検知します。
+/// レーダー水平線（`radar_horizon`）を有効にしたセンサーは、地球の曲率で見通しのない
+/// ターゲットを検知しません。
+/// 探知確率モデル（`detection_probability`）を持つセンサーは観測ごとに探知の成否を判定し、
+/// M-of-N で航跡を確立します。探知の判定は実効探知範囲を超えて計測範囲まで行います。
 #[derive(Debug, Clone)]
 pub struct Sensor {
     /// センサーの一意識別子
@@ -17,12 +53,133 @@ pub struct Sensor {
     pub position: Position3D,
     /// 探知範囲（メートル、球形半径）
     pub detection_range: f64,
+    /// 探知範囲の基準となるレーダー反射断面積（平方メートル）
+    pub reference_rcs: f64,
     /// センサーの現在状態
     pub status: AgentStatus,
     /// 現在検知中のターゲットIDセット
     pub detected_targets: HashSet<String>,
     /// 検知イベントの履歴
     pub detection_history: Vec<DetectionEvent>,
+    /// 視野（Noneの場合は全方位）
+    pub field_of_view: Option<FieldOfView>,
+    /// 回転走査（Noneの場合は常時全視野を監視）
+    pub scan: Option<ScanPattern>,
+    /// レーダー水平線による見通し判定を行うか
+    pub radar_horizon: bool,
+    /// 観測雑音（Noneの場合は観測値を生成しない）
+    pub measurement_noise: Option<MeasurementNoise>,
+    /// 探知確率モデル（Noneの場合は探知範囲内で常に探知）
+    pub detection_probability: Option<DetectionProbability>,
+    /// 探知の更新周期（秒、Noneの場合は毎ステップ更新）
+    pub update_period: Option<f64>,
+    /// 探知結果が指揮所に届くまでの報告遅延（秒）
+    pub report_latency: f64,
+    /// 同時に維持できる航跡数（Noneの場合は無制限）
+    pub max_tracks: Option<usize>,
+    /// 航跡容量を超えた場合の優先順位
+    pub track_priority: TrackPriority,
+    /// 指揮所から通知された目標優先度順のターゲットID
+    priority_feedback: Vec<String>,
+    /// 航跡容量の飽和に関する統計
+    pub saturation: SaturationStats,
+    /// 容量超過で航跡を開始できなかったことのあるターゲットID
+    rejected_targets: HashSet<String>,
+    /// 次に探知を更新する時刻
+    next_update_time: f64,
+    /// 探知判定用の乱数生成器
+    detection_rng: ChaCha8Rng,
+    /// ターゲットIDごとの観測結果の履歴
+    look_history: HashMap<String, LookHistory>,
+    /// 今回の検知処理で実際に探知したターゲットID
+    observed_targets: HashSet<String>,
+    /// 前回の検知処理の時刻（走査範囲の計算に使用）
+    last_detection_time: Option<f64>,
+    /// ターゲットIDごとのビームが最後に掃引した時刻
+    last_swept: HashMap<String, f64>,
+}
+
+/// センサーの視野
+/// 
+/// 方位角は `angle_reference` の規約（+X軸を0度とし反時計回りを正）、
+/// 仰角は水平面を0度とし上向きを正とします。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FieldOfView {
+    /// 視野中心（ボアサイト）の方位角（度）
+    pub boresight_az_deg: f64,
+    /// 方位方向の視野幅（度、ボアサイトを中心とした全幅）
+    pub azimuth_width_deg: f64,
+    /// 最低仰角（度）
+    pub min_elevation_deg: f64,
+    /// 最高仰角（度）
+    pub max_elevation_deg: f64,
+}
+
+impl FieldOfView {
+    /// 方位角が視野の方位範囲内にあるか
+    pub fn covers_azimuth(&self, azimuth_deg: f64) -> bool {
+        math_utils::angle_difference(self.boresight_az_deg, azimuth_deg).abs() <= self.azimuth_width_deg / 2.0
+    }
+
+    /// センサー位置から見た位置が視野内にあるか
+    /// 
+    /// # 引数
+    /// 
+    /// * `origin` - センサー位置
+    /// * `position` - 判定する位置
+    pub fn contains(&self, origin: &Position3D, position: &Position3D) -> bool {
+        let elevation_deg = (position.z - origin.z).atan2(origin.distance_xy(position)).to_degrees();
+        self.covers_azimuth(azimuth_deg(origin, position))
+            && (self.min_elevation_deg..=self.max_elevation_deg).contains(&elevation_deg)
+    }
+}
+
+/// 回転走査パターン
+/// 
+/// ビームは時刻0に `start_az_deg` を向き、`rpm` で反時計回りに回転します。
+/// ビーム幅の範囲が掃引した方位のターゲットのみを検知し、検知済みのターゲットは
+/// 1走査周期の間は掃引されなくても追跡を継続します。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScanPattern {
+    /// 毎分回転数
+    pub rpm: f64,
+    /// ビーム幅（度、方位方向の全幅）
+    pub beam_width_deg: f64,
+    /// 時刻0のビーム方位角（度）
+    pub start_az_deg: f64,
+}
+
+impl ScanPattern {
+    /// 1回転に要する時間（秒）
+    pub fn period(&self) -> f64 {
+        60.0 / self.rpm
+    }
+
+    /// 時刻におけるビーム中心の方位角（度）
+    pub fn beam_azimuth_at(&self, time: f64) -> f64 {
+        self.start_az_deg + 6.0 * self.rpm * time
+    }
+
+    /// 期間内にビームが方位角を掃引したか
+    /// 
+    /// # 引数
+    /// 
+    /// * `azimuth_deg` - 判定する方位角（度）
+    /// * `from_time` - 期間の開始時刻（秒）
+    /// * `to_time` - 期間の終了時刻（秒）
+    pub fn sweeps(&self, azimuth_deg: f64, from_time: f64, to_time: f64) -> bool {
+        let swept_deg = 6.0 * self.rpm * (to_time - from_time).max(0.0) + self.beam_width_deg;
+        if swept_deg >= 360.0 {
+            return true;
+        }
+        let trailing_edge = self.beam_azimuth_at(from_time) - self.beam_width_deg / 2.0;
+        (azimuth_deg - trailing_edge).rem_euclid(360.0) <= swept_deg
+    }
+}
+
+/// 原点から見た位置の方位角（度）
+fn azimuth_deg(origin: &Position3D, position: &Position3D) -> f64 {
+    (position.y - origin.y).atan2(position.x - origin.x).to_degrees()
 }
 
 /// 検知イベント
@@ -38,6 +195,8 @@ pub struct DetectionEvent {
     pub target_position: Position3D,
     /// センサーからターゲットまでの距離（メートル）
     pub distance: f64,
+    /// ターゲットのRCSに対する実効探知範囲（メートル）
+    pub effective_range: f64,
     /// 検知イベントの種類
     pub event_type: DetectionEventType,
 }
@@ -52,7 +211,41 @@ pub enum DetectionEventType {
     /// ターゲットを追跡中
     Tracking,
     /// ターゲットをロストした
-    Lost,
+    Lost(LostReason),
+}
+
+/// ロストの理由
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LostReason {
+    /// 探知できなくなった（探知範囲外・見通しなし・未探知の継続・撃破等）
+    NotDetected,
+    /// 航跡容量を超えたため優先度の低い航跡として破棄した
+    Saturated,
+}
+
+/// 航跡容量を超えた場合に維持する航跡の優先順位
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TrackPriority {
+    /// センサーからの距離が近い順
+    #[default]
+    Range,
+    /// 指揮所の目標優先度順（指揮所が把握していないターゲットはその後に距離順）
+    CommandPost,
+}
+
+/// 航跡容量の飽和に関する統計
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SaturationStats {
+    /// 探知の更新回数
+    pub updates: u32,
+    /// 追跡要求が航跡容量を超えた更新回数
+    pub saturated_updates: u32,
+    /// 容量超過で破棄した確立済みの航跡数
+    pub dropped_tracks: u32,
+    /// 容量超過で航跡を開始できなかったターゲット数（同じターゲットは繰り返し棄却されても1回と数える）
+    pub rejected_detections: u32,
+    /// 同時に追跡を要求されたターゲット数の最大値
+    pub peak_demand: usize,
 }
 
 impl Sensor {
@@ -71,16 +264,95 @@ impl Sensor {
             id,
             position,
             detection_range: 0.0,               // initializeで設定
+            reference_rcs: REFERENCE_RCS_M2,    // initializeで設定
             status: AgentStatus::Active,
             detected_targets: HashSet::new(),
             detection_history: Vec::new(),
+            field_of_view: None,                // initializeで設定
+            scan: None,                         // initializeで設定
+            radar_horizon: false,               // initializeで設定
+            measurement_noise: None,            // initializeで設定
+            detection_probability: None,        // set_detection_probabilityで設定
+            update_period: None,                // initializeで設定
+            report_latency: 0.0,                // initializeで設定
+            max_tracks: None,                   // initializeで設定
+            track_priority: TrackPriority::Range, // initializeで設定
+            priority_feedback: Vec::new(),
+            saturation: SaturationStats::default(),
+            rejected_targets: HashSet::new(),
+            next_update_time: 0.0,
+            detection_rng: ChaCha8Rng::seed_from_u64(0),
+            look_history: HashMap::new(),
+            observed_targets: HashSet::new(),
+            last_detection_time: None,
+            last_swept: HashMap::new(),
         }
     }
 
+    /// 探知確率モデルを設定
+    /// 
+    /// # 引数
+    /// 
+    /// * `model` - 探知確率モデル
+    /// * `seed` - 探知判定用の乱数シード
+    pub fn set_detection_probability(&mut self, model: DetectionProbability, seed: u64) {
+        self.detection_probability = Some(model);
+        self.detection_rng = ChaCha8Rng::seed_from_u64(seed);
+        self.look_history.clear();
+    }
+
+    /// 指揮所の目標優先度を通知
+    /// 
+    /// 航跡容量の優先順位が `TrackPriority::CommandPost` の場合に、維持する航跡の選択に使用します。
+    /// 
+    /// # 引数
+    /// 
+    /// * `target_ids` - 優先度の高い順のターゲットID
+    pub fn set_priority_feedback(&mut self, target_ids: Vec<String>) {
+        self.priority_feedback = target_ids;
+    }
+
+    /// 探知の更新時刻に達しているか（更新周期未設定の場合は常にtrue）
+    /// 
+    /// # 引数
+    /// 
+    /// * `current_time` - 現在のシミュレーション時刻（秒）
+    pub fn is_update_due(&self, current_time: f64) -> bool {
+        current_time + 1e-9 >= self.next_update_time
+    }
+
+    /// 位置への見通しがあるか（レーダー水平線が無効の場合は常にtrue）
+    pub fn has_line_of_sight(&self, position: &Position3D) -> bool {
+        !self.radar_horizon
+            || self.position.distance_3d(position) <= radar_horizon_range(self.position.z, position.z)
+    }
+
+    /// 方位角がセンサーの視野の方位範囲内にあるか（視野未設定の場合は常にtrue）
+    pub fn covers_azimuth(&self, azimuth_deg: f64) -> bool {
+        self.field_of_view.is_none_or(|fov| fov.covers_azimuth(azimuth_deg))
+    }
+
+    /// ターゲットのRCSに対する実効探知範囲
+    /// 
+    /// # 引数
+    /// 
+    /// * `rcs_m2` - ターゲットのレーダー反射断面積（平方メートル）
+    /// 
+    /// # 戻り値
+    /// 
+    /// 実効探知範囲（メートル）
+    pub fn effective_range(&self, rcs_m2: f64) -> f64 {
+        self.detection_range * (rcs_m2.max(0.0) / self.reference_rcs).powf(0.25)
+    }
+
     /// ターゲットの検知処理
     /// 
     /// 指定されたターゲットリストに対して検知処理を実行し、
     /// 初回検知、追跡、ロストのイベントを記録します。
+    /// 回転走査を行うセンサーは、前回の検知処理から今回までにビームが掃引したターゲットのみを
+    /// 新たに検知し、検知済みのターゲットは最後の掃引から1走査周期以内であれば追跡を継続します。
+    /// 探知確率モデルを持つセンサーは観測ごとに探知を判定し、直近N回中M回以上の探知で初回検知、
+    /// 直近N回で一度も探知しなかった時点でロストとします。
     /// 
     /// # 引数
     /// 
@@ -93,6 +365,13 @@ impl Sensor {
     pub fn detect_targets(&mut self, targets: &[Target], current_time: f64) -> Vec<String> {
         let mut newly_detected = Vec::new();
         let mut currently_detected = HashSet::new();
+        let mut covered_targets = HashSet::new();
+        // ターゲットIDごとのセンサーからの距離
+        let mut distances = HashMap::new();
+        let mut events = Vec::new();
+        self.observed_targets.clear();
+        let scan_start_time = self.last_detection_time.unwrap_or(current_time);
+        self.last_detection_time = Some(current_time);
 
         for target in targets {
             if !target.is_active() {
@@ -100,9 +379,55 @@ impl Sensor {
             }
 
             let distance = self.position.distance_3d(&target.position);
+            distances.insert(target.id.clone(), distance);
+            let effective_range = self.effective_range(target.rcs_m2);
+            // 探知確率モデルでは距離とともに低下する探知確率で判定するため、計測範囲まで観測する
+            let coverage_range = self.detection_probability
+                .map_or(effective_range, |model| model.instrumented_range(effective_range));
+            let in_coverage = distance <= coverage_range && self.has_line_of_sight(&target.position)
+                && self.field_of_view.is_none_or(|fov| fov.contains(&self.position, &target.position));
+            if in_coverage {
+                covered_targets.insert(target.id.clone());
+            }
+            
+            if in_coverage && let Some(scan) = self.scan {
+                if scan.sweeps(azimuth_deg(&self.position, &target.position), scan_start_time, current_time) {
+                    self.last_swept.insert(target.id.clone(), current_time);
+                } else {
+                    // 掃引されていない検知済みターゲットは1走査周期（＋1ステップの余裕）の間だけ追跡を継続
+                    let hold_time = scan.period() + (current_time - scan_start_time);
+                    let held = self.detected_targets.contains(&target.id)
+                        && self.last_swept.get(&target.id).is_some_and(|swept| current_time - swept <= hold_time);
+                    if held {
+                        currently_detected.insert(target.id.clone());
+                    }
+                    continue;
+                }
+            }
             
-            if distance <= self.detection_range {
+            if in_coverage && let Some(model) = self.detection_probability {
+                let hit = self.detection_rng.gen_bool(model.single_look_pd(distance, effective_range));
+                let history = self.look_history.entry(target.id.clone()).or_default();
+                history.record(hit);
+                let hits = history.hits_in_last(model.confirm_looks);
+                let tracked = if self.detected_targets.contains(&target.id) {
+                    hits > 0
+                } else {
+                    hits >= model.confirm_hits
+                };
+                if !tracked {
+                    continue;
+                }
+                if !hit {
+                    // 確立済みの航跡は探知できなかった観測でも維持
+                    currently_detected.insert(target.id.clone());
+                    continue;
+                }
+            }
+            
+            if in_coverage {
                 currently_detected.insert(target.id.clone());
+                self.observed_targets.insert(target.id.clone());
                 
                 // 初回検知かどうか
                 let is_newly_detected = !self.detected_targets.contains(&target.id);
@@ -111,47 +436,98 @@ impl Sensor {
                     newly_detected.push(target.id.clone());
                     
                     // 検知イベントを記録
-                    self.detection_history.push(DetectionEvent {
+                    events.push(DetectionEvent {
                         timestamp: current_time,
                         target_id: target.id.clone(),
                         target_position: target.position,
                         distance,
+                        effective_range,
                         event_type: DetectionEventType::FirstDetected,
                     });
                 } else {
                     // 追跡中イベントを記録
-                    self.detection_history.push(DetectionEvent {
+                    events.push(DetectionEvent {
                         timestamp: current_time,
                         target_id: target.id.clone(),
                         target_position: target.position,
                         distance,
+                        effective_range,
                         event_type: DetectionEventType::Tracking,
                     });
                 }
             }
         }
 
+        // 航跡容量を超えた場合は優先度の低いターゲットを破棄
+        self.saturation.updates += 1;
+        self.saturation.peak_demand = self.saturation.peak_demand.max(currently_detected.len());
+        let mut dropped = HashSet::new();
+        if let Some(max_tracks) = self.max_tracks
+            && currently_detected.len() > max_tracks
+        {
+            let mut ranked: Vec<String> = currently_detected.iter().cloned().collect();
+            ranked.sort_by(|a, b| {
+                self.track_rank(a)
+                    .cmp(&self.track_rank(b))
+                    .then(distances[a].total_cmp(&distances[b]))
+                    .then(a.cmp(b))
+            });
+            for target_id in ranked.split_off(max_tracks) {
+                currently_detected.remove(&target_id);
+                self.observed_targets.remove(&target_id);
+                if self.detected_targets.contains(&target_id) {
+                    self.saturation.dropped_tracks += 1;
+                } else if self.rejected_targets.insert(target_id.clone()) {
+                    self.saturation.rejected_detections += 1;
+                }
+                dropped.insert(target_id);
+            }
+            self.saturation.saturated_updates += 1;
+        }
+        self.detection_history.extend(events.into_iter().filter(|event| !dropped.contains(&event.target_id)));
+
         // ロストしたターゲットの処理
         for target_id in &self.detected_targets {
             if !currently_detected.contains(target_id) {
+                let reason = if dropped.contains(target_id) {
+                    LostReason::Saturated
+                } else {
+                    LostReason::NotDetected
+                };
                 // ロストイベントを記録
                 self.detection_history.push(DetectionEvent {
                     timestamp: current_time,
                     target_id: target_id.clone(),
                     target_position: Position3D::new(0.0, 0.0, 0.0), // 不明
                     distance: 0.0,
-                    event_type: DetectionEventType::Lost,
+                    effective_range: 0.0,
+                    event_type: DetectionEventType::Lost(reason),
                 });
             }
         }
 
         // 検知状態を更新
+        self.last_swept.retain(|target_id, _| currently_detected.contains(target_id));
+        self.look_history.retain(|target_id, _| covered_targets.contains(target_id));
         self.detected_targets = currently_detected.clone();
         
         // 現在検知中の全ターゲットIDを返す
         currently_detected.into_iter().collect()
     }
 
+    /// 航跡容量を超えた場合の優先順位（小さいほど優先）
+    /// 
+    /// 距離順の場合は全ターゲットが同順位で、呼び出し側が距離で並べます。
+    fn track_rank(&self, target_id: &str) -> usize {
+        match self.track_priority {
+            TrackPriority::Range => 0,
+            TrackPriority::CommandPost => self.priority_feedback
+                .iter()
+                .position(|id| id == target_id)
+                .unwrap_or(self.priority_feedback.len()),
+        }
+    }
+
     /// 特定のターゲットとの距離を計算
     /// 
     /// # 引数
@@ -206,7 +582,7 @@ impl Sensor {
             .count();
         let lost_detections = self.detection_history
             .iter()
-            .filter(|event| event.event_type == DetectionEventType::Lost)
+            .filter(|event| matches!(event.event_type, DetectionEventType::Lost(_)))
             .count();
         let currently_tracking = self.detected_targets.len();
 
@@ -248,6 +624,39 @@ impl Sensor {
     /// 検知範囲内にある場合はtrue
     pub fn is_in_detection_range(&self, position: Position3D) -> bool {
         self.position.distance_3d(&position) <= self.detection_range
+            && self.has_line_of_sight(&position)
+            && self.field_of_view.is_none_or(|fov| fov.contains(&self.position, &position))
+    }
+
+    /// 今回の検知処理で観測したターゲットの雑音付き観測値を生成
+    /// 
+    /// 回転走査を行うセンサーは今回ビームが掃引したターゲット、探知確率モデルを持つセンサーは
+    /// 今回探知に成功したターゲットのみを観測します。
+    /// 観測雑音が未設定の場合は空のベクターを返します。
+    /// 
+    /// # 引数
+    /// 
+    /// * `targets` - ターゲットのスライス
+    /// * `rng` - 乱数生成器
+    /// * `current_time` - 現在のシミュレーション時刻（秒）
+    /// 
+    /// # 戻り値
+    /// 
+    /// ターゲットIDと観測値の組のベクター
+    pub fn measure_targets<R: Rng + ?Sized>(
+        &self,
+        targets: &[Target],
+        rng: &mut R,
+        current_time: f64,
+    ) -> Vec<(String, Measurement)> {
+        let Some(noise) = self.measurement_noise else {
+            return Vec::new();
+        };
+        targets
+            .iter()
+            .filter(|target| self.observed_targets.contains(&target.id))
+            .map(|target| (target.id.clone(), noise.measure(rng, &self.position, &target.position, current_time)))
+            .collect()
     }
 
     /// ターゲット検知の更新（シミュレーションエンジン用）
@@ -261,6 +670,12 @@ impl Sensor {
     /// * `current_time` - 現在のシミュレーション時刻（秒）
     pub fn update_detections(&mut self, targets: &[Target], current_time: f64) {
         self.detect_targets(targets, current_time);
+        if let Some(period) = self.update_period {
+            // 時間刻みが周期の約数でない場合も平均の更新間隔が周期となるよう、予定時刻を周期ずつ進める
+            while self.next_update_time <= current_time + 1e-9 {
+                self.next_update_time += period;
+            }
+        }
     }
 }
 
@@ -284,11 +699,45 @@ impl IAgent for Sensor {
         self.status = AgentStatus::Active;
         self.detected_targets.clear();
         self.detection_history.clear();
+        self.last_detection_time = None;
+        self.last_swept.clear();
+        self.look_history.clear();
+        self.observed_targets.clear();
+        self.next_update_time = 0.0;
+        self.priority_feedback.clear();
+        self.saturation = SaturationStats::default();
+        self.rejected_targets.clear();
+        
+        self.radar_horizon = scenario_config.world.radar_horizon;
         
         // シナリオからセンサー設定を探して適用
         for sensor_config in &scenario_config.friendly_forces.sensors {
             if sensor_config.id == self.id {
                 self.detection_range = sensor_config.range_m;
+                self.reference_rcs = sensor_config.reference_rcs_m2;
+                self.field_of_view = sensor_config.field_of_view.as_ref().map(|fov| FieldOfView {
+                    boresight_az_deg: fov.boresight_az_deg,
+                    azimuth_width_deg: fov.azimuth_width_deg,
+                    min_elevation_deg: fov.min_elevation_deg,
+                    max_elevation_deg: fov.max_elevation_deg,
+                });
+                self.scan = sensor_config.scan.as_ref().map(|scan| ScanPattern {
+                    rpm: scan.rpm,
+                    beam_width_deg: scan.beam_width_deg,
+                    start_az_deg: scan.start_az_deg,
+                });
+                self.measurement_noise = sensor_config.measurement_noise.as_ref().map(|noise| MeasurementNoise {
+                    range_sigma: noise.range_sigma_m,
+                    azimuth_sigma_deg: noise.azimuth_sigma_deg,
+                    elevation_sigma_deg: noise.elevation_sigma_deg,
+                });
+                self.update_period = sensor_config.update_period_s;
+                self.report_latency = sensor_config.report_latency_s;
+                self.max_tracks = sensor_config.max_tracks;
+                self.track_priority = match sensor_config.track_priority {
+                    crate::scenario::TrackPriorityKind::Range => TrackPriority::Range,
+                    crate::scenario::TrackPriorityKind::CommandPost => TrackPriority::CommandPost,
+                };
                 break;
             }
         }
@@ -308,7 +757,7 @@ impl IAgent for Sensor {
         }
     }
 
-    fn tick(&mut self, dt: f64) {
+    fn tick(&mut self, _dt: f64) {
         // センサーは基本的に受動的なデバイス
         // 実際の検知処理は外部から detect_targets が呼ばれることで実行される
         
@@ -318,12 +767,10 @@ impl IAgent for Sensor {
         }
         
         // 古い検知履歴の削除（メモリ管理）
-        static mut CURRENT_TIME: f64 = 0.0;
-        unsafe {
-            CURRENT_TIME += dt;
-            
+        // 全センサーで共有する経過時間ではなく、このセンサーの最新の検知イベントの時刻を基準にする
+        if let Some(latest) = self.detection_history.last().map(|event| event.timestamp) {
             // 60秒より古い履歴は削除
-            let cutoff_time = CURRENT_TIME - 60.0;
+            let cutoff_time = latest - 60.0;
             self.detection_history.retain(|event| event.timestamp >= cutoff_time);
         }
     }
@@ -355,6 +802,21 @@ impl ISensor for Sensor {
     }
 }
 
+/// センサーから指揮所への報告
+/// 
+/// 更新周期に達したセンサーの探知結果と観測値で、報告遅延後に指揮所へ配信されます。
+#[derive(Debug, Clone)]
+pub struct SensorReport {
+    /// 指揮所に届く時刻
+    pub deliver_time: f64,
+    /// 報告元のセンサーID
+    pub sensor_id: String,
+    /// 報告時点で検知中のターゲットID
+    pub detected_targets: Vec<String>,
+    /// 報告時点の観測値
+    pub measurements: Vec<(String, Measurement)>,
+}
+
 /// センサーネットワーク管理用のヘルパー構造体
 /// 
 /// 複数のセンサーを一括管理し、ネットワーク全体での検知処理や
@@ -365,6 +827,8 @@ pub struct SensorNetwork {
     pub sensors: Vec<Sensor>,
     /// データ融合機能の有効/無効
     pub fusion_enabled: bool,
+    /// 報告の観測を融合したシステム航跡（データ融合が有効な場合に使用）
+    pub tracker: SystemTracker,
 }
 
 impl SensorNetwork {
@@ -377,6 +841,7 @@ impl SensorNetwork {
         Self {
             sensors: Vec::new(),
             fusion_enabled: true,
+            tracker: SystemTracker::new(DEFAULT_GATE, DEFAULT_COAST_TIME_S, DEFAULT_PROCESS_NOISE_MPS2),
         }
     }
 
@@ -427,6 +892,57 @@ impl SensorNetwork {
         }
     }
 
+    /// 更新周期に達したセンサーの探知を更新し、指揮所への報告を作成
+    /// 
+    /// 航跡容量の優先順位が指揮所の目標優先度のセンサーには、探知の前に `priority_feedback` を通知します。
+    /// 
+    /// # 引数
+    /// 
+    /// * `targets` - 検知対象のターゲットスライス
+    /// * `rng` - 観測雑音の乱数生成器
+    /// * `current_time` - 現在のシミュレーション時刻（秒）
+    /// * `dt` - 時間刻み（秒）
+    /// * `priority_feedback` - 指揮所の目標優先度順のターゲットID
+    /// 
+    /// # 戻り値
+    /// 
+    /// 探知を更新したセンサーの報告（配信時刻は報告遅延を加えた時刻）
+    pub fn collect_reports<R: Rng + ?Sized>(
+        &mut self,
+        targets: &[Target],
+        rng: &mut R,
+        current_time: f64,
+        dt: f64,
+        priority_feedback: &[String],
+    ) -> Vec<SensorReport> {
+        let mut reports = Vec::new();
+        for sensor in self.sensors.iter_mut().filter(|sensor| sensor.is_active()) {
+            if sensor.is_update_due(current_time) {
+                if sensor.track_priority == TrackPriority::CommandPost {
+                    sensor.set_priority_feedback(priority_feedback.to_vec());
+                }
+                sensor.update_detections(targets, current_time);
+                reports.push(SensorReport {
+                    deliver_time: current_time + sensor.report_latency,
+                    sensor_id: sensor.id.clone(),
+                    detected_targets: sensor.get_detected_targets(),
+                    measurements: sensor.measure_targets(targets, rng, current_time),
+                });
+            }
+            sensor.tick(dt);
+        }
+        reports
+    }
+
+    /// 指揮所に届いた報告の観測をシステム航跡に融合
+    /// 
+    /// # 引数
+    /// 
+    /// * `report` - 配信時刻に達した報告
+    pub fn fuse_report(&mut self, report: SensorReport) {
+        self.tracker.fuse(&report.sensor_id, report.measurements);
+    }
+
     /// ネットワーク全体の検知統計
     /// 
     /// # 戻り値
@@ -461,4 +977,177 @@ impl SensorNetwork {
             })
             .collect()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_of_view_and_scan_sweep() {
+        let origin = Position3D::new(0.0, 0.0, 0.0);
+        let fov = FieldOfView {
+            boresight_az_deg: 90.0,
+            azimuth_width_deg: 120.0,
+            min_elevation_deg: 0.0,
+            max_elevation_deg: 30.0,
+        };
+        assert!(fov.contains(&origin, &Position3D::new(1_000.0, 10_000.0, 1_000.0)));
+        assert!(!fov.contains(&origin, &Position3D::new(10_000.0, 1_000.0, 1_000.0)));
+        assert!(!fov.contains(&origin, &Position3D::new(0.0, 1_000.0, 2_000.0)));
+
+        // 12 rpm（72度/秒）、ビーム幅2度、0.1秒刻みで1回転を走査
+        let scan = ScanPattern { rpm: 12.0, beam_width_deg: 2.0, start_az_deg: 0.0 };
+        assert!(scan.sweeps(5.0, 0.0, 0.1));
+        assert!(!scan.sweeps(90.0, 0.0, 0.1));
+        assert!(scan.sweeps(90.0, 1.2, 1.3));
+        let sweeps_per_revolution = (0..50)
+            .filter(|step| scan.sweeps(-45.0, *step as f64 * 0.1, (*step + 1) as f64 * 0.1))
+            .count();
+        assert_eq!(sweeps_per_revolution, 1);
+    }
+
+    #[test]
+    fn test_radar_horizon_masks_low_altitude_target() {
+        // 高度50mのセンサーと高度100mのターゲットの水平線距離は約70km
+        let horizon = radar_horizon_range(50.0, 100.0);
+        assert!((horizon - 70_400.0).abs() < 500.0, "{}", horizon);
+
+        let mut sensor = Sensor::new("S001".to_string(), Position3D::new(0.0, 0.0, 50.0));
+        sensor.detection_range = 200_000.0;
+        assert!(sensor.is_in_detection_range(Position3D::new(180_000.0, 0.0, 100.0)));
+        sensor.radar_horizon = true;
+        assert!(!sensor.is_in_detection_range(Position3D::new(180_000.0, 0.0, 100.0)));
+        assert!(sensor.is_in_detection_range(Position3D::new(180_000.0, 0.0, 5_000.0)));
+    }
+
+    #[test]
+    fn test_pd_model_detects_beyond_effective_range() {
+        let targets: Vec<Target> = [130_000.0, 250_000.0]
+            .iter()
+            .enumerate()
+            .map(|(i, x)| {
+                let mut target = Target::new(
+                    format!("T00{}", i + 1),
+                    Position3D::new(*x, 0.0, 1000.0),
+                    Position3D::new(0.0, 0.0, 0.0),
+                    "G001".to_string(),
+                );
+                target.check_spawn(0.0);
+                target
+            })
+            .collect();
+        let mut sensor = Sensor::new("S001".to_string(), Position3D::new(0.0, 0.0, 50.0));
+        sensor.detection_range = 100_000.0;
+        let model = DetectionProbability {
+            reference_snr: 20.0,
+            false_alarm_probability: 1e-6,
+            threshold: None,
+            confirm_hits: 1,
+            confirm_looks: 1,
+        };
+        sensor.set_detection_probability(model, 3);
+
+        // 実効探知範囲の1.3倍（Pd 約0.18）では探知されることがあり、計測範囲（約2.1倍）の外では探知しない
+        let mut detections = [0, 0];
+        for step in 0..50 {
+            for target_id in sensor.detect_targets(&targets, step as f64) {
+                detections[if target_id == "T001" { 0 } else { 1 }] += 1;
+            }
+        }
+        assert!(detections[0] > 0 && detections[0] < 50, "{:?}", detections);
+        assert_eq!(detections[1], 0);
+    }
+
+    #[test]
+    fn test_detection_history_pruned_per_sensor() {
+        let mut target = Target::new(
+            "T001".to_string(),
+            Position3D::new(10_000.0, 0.0, 1000.0),
+            Position3D::new(0.0, 0.0, 0.0),
+            "G001".to_string(),
+        );
+        target.check_spawn(0.0);
+        let targets = vec![target];
+        let mut sensors: Vec<Sensor> = ["S001", "S002"]
+            .iter()
+            .map(|id| {
+                let mut sensor = Sensor::new(id.to_string(), Position3D::new(0.0, 0.0, 50.0));
+                sensor.detection_range = 100_000.0;
+                sensor
+            })
+            .collect();
+
+        // 複数のセンサーを100秒間更新しても、各センサーは直近60秒の履歴を保持
+        for step in 0..1000 {
+            for sensor in &mut sensors {
+                sensor.detect_targets(&targets, step as f64 * 0.1);
+                sensor.tick(0.1);
+            }
+        }
+        for sensor in &sensors {
+            let first = sensor.detection_history.first().unwrap().timestamp;
+            let last = sensor.detection_history.last().unwrap().timestamp;
+            assert!((last - 99.9).abs() < 1e-9, "{}", last);
+            assert!(first >= last - 60.0 && first < last - 59.0, "{}", first);
+        }
+    }
+
+    #[test]
+    fn test_update_period_keeps_average_interval() {
+        let mut sensor = Sensor::new("S001".to_string(), Position3D::new(0.0, 0.0, 50.0));
+        sensor.detection_range = 100_000.0;
+        sensor.update_period = Some(0.25);
+
+        // 時間刻み0.1秒で周期0.25秒の更新は、間隔0.3秒と0.2秒を交互に繰り返す
+        let mut updates = Vec::new();
+        for step in 0..=10 {
+            let time = step as f64 * 0.1;
+            if sensor.is_update_due(time) {
+                sensor.update_detections(&[], time);
+                updates.push(step);
+            }
+        }
+        assert_eq!(updates, vec![0, 3, 5, 8, 10]);
+    }
+
+    #[test]
+    fn test_saturated_sensor_keeps_priority_tracks() {
+        let targets: Vec<Target> = [10_000.0, 20_000.0, 30_000.0]
+            .iter()
+            .enumerate()
+            .map(|(i, x)| {
+                let mut target = Target::new(
+                    format!("T00{}", i + 1),
+                    Position3D::new(*x, 0.0, 1000.0),
+                    Position3D::new(0.0, 0.0, 0.0),
+                    "G001".to_string(),
+                );
+                target.check_spawn(0.0);
+                target
+            })
+            .collect();
+        let mut sensor = Sensor::new("S001".to_string(), Position3D::new(0.0, 0.0, 50.0));
+        sensor.detection_range = 100_000.0;
+        sensor.max_tracks = Some(2);
+
+        let mut detected = sensor.detect_targets(&targets, 0.0);
+        detected.sort();
+        assert_eq!(detected, vec!["T001", "T002"]);
+        assert_eq!(sensor.saturation.rejected_detections, 1);
+        // 同じターゲットを再び棄却しても数は増えない
+        let mut rescanned = sensor.clone();
+        rescanned.detect_targets(&targets, 0.5);
+        assert_eq!((rescanned.saturation.saturated_updates, rescanned.saturation.rejected_detections), (2, 1));
+
+        // 指揮所の優先度で最遠のターゲットを優先すると、優先度のない航跡のうち遠い方を破棄
+        sensor.track_priority = TrackPriority::CommandPost;
+        sensor.set_priority_feedback(vec!["T003".to_string()]);
+        let mut detected = sensor.detect_targets(&targets, 1.0);
+        detected.sort();
+        assert_eq!(detected, vec!["T001", "T003"]);
+        let lost = sensor.detection_history.last().unwrap();
+        assert_eq!((lost.target_id.as_str(), &lost.event_type), ("T002", &DetectionEventType::Lost(LostReason::Saturated)));
+        assert_eq!((sensor.saturation.saturated_updates, sensor.saturation.dropped_tracks), (2, 1));
+    }
+}
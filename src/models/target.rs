@@ -1,12 +1,71 @@
 use crate::models::{
     traits::{IAgent, IMovable},
     common::{Position3D, Velocity3D, AgentStatus},
+    maneuver::{ActiveManeuver, ManeuverProfile, GRAVITY_MPS2},
+    ballistic::{BallisticShape, BallisticTrajectory},
+    cruise::CruiseProfile,
+    sensor::REFERENCE_RCS_M2,
 };
-use tracing::{info, warn, error};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use tracing::{info, warn, error, debug};
+
+/// 経路点
+/// 
+/// ターゲットが目的地への最終進入前に順に通過する位置と、その区間の速度です。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Waypoint {
+    /// 経路点の位置（高度を含む）
+    pub position: Position3D,
+    /// 直前の位置からこの経路点までの移動速度（m/s）
+    pub speed: f64,
+}
+
+/// 高度プロファイル
+/// 
+/// 最終進入中のターゲットは巡航高度を保ち、目的地までの水平距離が
+/// 降下開始距離を下回るとダイブ角で降下します（地表高度0まで）。
+/// 高度の変化率はダイブ角に相当する勾配（上昇も同じ）に制限されます。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AltitudeProfile {
+    /// 巡航高度（メートル）
+    pub cruise_altitude: f64,
+    /// 降下を開始する目的地までの水平距離（メートル）
+    pub descent_start: f64,
+    /// 終末ダイブ角（度、水平からの角度）
+    pub dive_angle_deg: f64,
+}
+
+impl AltitudeProfile {
+    /// 目的地までの水平距離に応じた目標高度
+    pub fn altitude_at(&self, distance_to_destination: f64) -> f64 {
+        if distance_to_destination >= self.descent_start {
+            return self.cruise_altitude;
+        }
+        let descent = (self.descent_start - distance_to_destination) * self.dive_angle_deg.to_radians().tan();
+        (self.cruise_altitude - descent).max(0.0)
+    }
+}
+
+/// 出現時のばらつき
+/// 
+/// 出現時刻・速度は ±値の一様分布、出現位置は半径内の水平方向一様分布で加えます。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpawnJitter {
+    /// 出現時刻のばらつき幅（秒）
+    pub time: f64,
+    /// 速度のばらつき幅（m/s）
+    pub speed: f64,
+    /// 出現位置のばらつき半径（メートル）
+    pub position: f64,
+    /// 乱数シード
+    pub seed: u64,
+}
 
 /// 敵ターゲットエージェント
 /// 
-/// 敵勢力を表すエージェントで、スポーンポイントから指揮所に向かって等速直線運動します。
+/// 敵勢力を表すエージェントで、スポーンポイントから（経路点があれば順に経由して）
+/// 指揮所に向かって等速直線運動します。
 /// 耐久値を持ち、ミサイルの攻撃でダメージを受け、突破判定や破壊処理を行います。
 #[derive(Debug, Clone)]
 pub struct Target {
@@ -16,10 +75,12 @@ pub struct Target {
     pub position: Position3D,
     /// ターゲットの速度ベクトル
     pub velocity: Velocity3D,
-    /// 目的地（指揮所位置）
+    /// 目的地（防護対象の位置）
     pub destination: Position3D,
     /// 目的地への到達判定範囲（メートル）
     pub arrival_radius: f64,
+    /// 攻撃目標の防護対象ID（指揮所の場合は指揮所ID）
+    pub asset_id: String,
     /// 現在の耐久値（残りHP）
     pub endurance: u32,
     /// 最大耐久値（初期HP）
@@ -32,6 +93,30 @@ pub struct Target {
     pub spawn_time: f64,
     /// 移動速度（m/s）
     pub speed: f64,
+    /// スポーン済みかどうか（領域外で消滅したターゲットの再出現を防ぐ）
+    pub spawned: bool,
+    /// 目的地への最終進入前に経由する経路点
+    pub waypoints: Vec<Waypoint>,
+    /// 次に向かう経路点のインデックス（`waypoints.len()`で最終進入中）
+    pub next_waypoint: usize,
+    /// 回避機動プロファイル（未設定の場合は回避しない）
+    pub maneuver: Option<ManeuverProfile>,
+    /// 実行中の回避機動
+    pub active_maneuver: Option<ActiveManeuver>,
+    /// 回避機動を行ったミサイルのID（同じミサイルに対する機動は1回のみ）
+    pub evaded_missiles: Vec<String>,
+    /// 重力弾道（弾道ミサイル型ターゲットの場合）
+    pub ballistic: Option<BallisticTrajectory>,
+    /// 低高度飛行プロファイル（巡航ミサイル型ターゲットの場合）
+    pub cruise: Option<CruiseProfile>,
+    /// 最終進入時の高度プロファイル（未設定の場合は高度を保つ）
+    pub altitude_profile: Option<AltitudeProfile>,
+    /// レーダー反射断面積（平方メートル）
+    pub rcs_m2: f64,
+    /// デコイ（センサーでは実目標と区別できず、到達しても突破として扱わない）
+    pub is_decoy: bool,
+    /// 指揮所がデコイと識別済みか
+    pub decoy_revealed: bool,
 }
 
 impl Target {
@@ -59,12 +144,25 @@ impl Target {
             velocity: Velocity3D::new(0.0, 0.0, 0.0),   // initializeで設定
             destination,
             arrival_radius: 0.0,                        // initializeで設定
+            asset_id: String::new(),                    // TargetGroupで設定
             endurance: 0,                               // initializeで設定
             max_endurance: 0,                           // initializeで設定
             status: AgentStatus::Inactive,              // spawn_timeまで非アクティブ
             group_id,
             spawn_time: 0.0,                            // initializeで設定
             speed: 0.0,                                 // initializeで設定
+            spawned: false,
+            waypoints: Vec::new(),                      // set_routeで設定
+            next_waypoint: 0,
+            maneuver: None,
+            active_maneuver: None,
+            evaded_missiles: Vec::new(),
+            ballistic: None,
+            cruise: None,
+            altitude_profile: None,
+            rcs_m2: REFERENCE_RCS_M2,
+            is_decoy: false,
+            decoy_revealed: false,
         }
     }
 
@@ -114,10 +212,40 @@ impl Target {
     /// 
     /// ターゲットが目的地（指揮所）の到達範囲内に達したかをチェックし、
     /// 到達した場合は状態をReachedに変更します。
+    /// 弾道ミサイル型は着弾時に判定し、到達範囲外への着弾は非アクティブとします。
+    /// デコイは到達しても突破とせず非アクティブとします。
     pub fn check_arrival(&mut self) {
+        if let Some(trajectory) = &self.ballistic {
+            if self.status != AgentStatus::Active || !trajectory.has_impacted() {
+                return;
+            }
+            let distance_to_destination = self.position.distance_xy(&self.destination);
+            if distance_to_destination > self.arrival_radius {
+                info!(
+                    target_id = %self.id,
+                    target_group = %self.group_id,
+                    position_x = self.position.x,
+                    position_y = self.position.y,
+                    distance_to_target = distance_to_destination,
+                    asset_id = %self.asset_id,
+                    "TARGET_IMPACT: ターゲットが到達範囲外に着弾しました"
+                );
+                self.status = AgentStatus::Inactive;
+                return;
+            }
+        }
         if self.status == AgentStatus::Active {
             let distance_to_destination = self.position.distance_xy(&self.destination);
-            if distance_to_destination <= self.arrival_radius {
+            if distance_to_destination <= self.arrival_radius && self.is_decoy {
+                info!(
+                    target_id = %self.id,
+                    target_group = %self.group_id,
+                    distance_to_target = distance_to_destination,
+                    asset_id = %self.asset_id,
+                    "DECOY_ARRIVED: デコイが目的地に到達しました（突破として扱いません）"
+                );
+                self.status = AgentStatus::Inactive;
+            } else if distance_to_destination <= self.arrival_radius {
                 warn!(
                     target_id = %self.id,
                     target_group = %self.group_id,
@@ -126,6 +254,7 @@ impl Target {
                     position_z = self.position.z,
                     distance_to_target = distance_to_destination,
                     arrival_radius = self.arrival_radius,
+                    asset_id = %self.asset_id,
                     "TARGET_REACHED: ターゲットが目的地に到達しました"
                 );
                 self.status = AgentStatus::Reached;
@@ -138,7 +267,13 @@ impl Target {
     /// ターゲットがシミュレーション領域外に出たかをチェックし、
     /// 領域外の場合は非アクティブ状態にして消滅させます。
     pub fn check_out_of_bounds(&mut self) {
-        if self.status == AgentStatus::Active && !self.position.is_in_simulation_bounds() {
+        // 弾道ミサイル型は高度制限を超えて飛翔するため水平方向のみ判定
+        let in_bounds = if self.ballistic.is_some() {
+            self.position.x.abs() <= 1_000_000.0 && self.position.y.abs() <= 1_000_000.0
+        } else {
+            self.position.is_in_simulation_bounds()
+        };
+        if self.status == AgentStatus::Active && !in_bounds {
             info!(
                 target_id = %self.id,
                 target_group = %self.group_id,
@@ -177,21 +312,246 @@ impl Target {
         self.spawn_time = spawn_time;
         self.speed = speed;
         
+        self.update_velocity();
+    }
+
+    /// 経路点を設定
+    /// 
+    /// ターゲットは経路点を順に通過した後、目的地へ最終進入します。
+    /// 
+    /// # 引数
+    /// 
+    /// * `waypoints` - 通過順の経路点
+    pub fn set_route(&mut self, waypoints: Vec<Waypoint>) {
+        self.waypoints = waypoints;
+        self.next_waypoint = 0;
+        self.update_velocity();
+    }
+
+    /// 重力弾道を設定（弾道ミサイル型ターゲット）
+    /// 
+    /// 現在位置を発射点とし、着弾点まで重力弾道で飛翔します。
+    /// 
+    /// # 引数
+    /// 
+    /// * `impact` - 着弾点
+    /// * `shape` - 最高到達高度または飛翔時間
+    /// 
+    /// # 戻り値
+    /// 
+    /// 弾道が成立した場合はtrue
+    pub fn set_ballistic(&mut self, impact: Position3D, shape: BallisticShape) -> bool {
+        let Some(trajectory) = BallisticTrajectory::new(self.position, impact, shape) else {
+            return false;
+        };
+        self.velocity = trajectory.initial_velocity;
+        self.speed = trajectory.initial_velocity.magnitude();
+        self.ballistic = Some(trajectory);
+        true
+    }
+
+    /// 低高度飛行プロファイルを設定（巡航ミサイル型ターゲット）
+    /// 
+    /// 現在位置の高度と経路点の高度を巡航高度に合わせます。
+    /// 
+    /// # 引数
+    /// 
+    /// * `profile` - 低高度飛行プロファイル
+    pub fn set_cruise(&mut self, profile: CruiseProfile) {
+        self.position.z = profile.altitude_at(self.position.distance_xy(&self.destination));
+        for waypoint in &mut self.waypoints {
+            waypoint.position.z = profile.cruise_altitude;
+        }
+        self.cruise = Some(profile);
+        self.update_velocity();
+    }
+
+    /// 交戦中のミサイルに対して回避機動を開始すべきか判定
+    /// 
+    /// 機動終了後に同じミサイルが終盤フェーズのままでも再度機動しないよう、
+    /// 機動を行ったミサイルは対象外とします。
+    /// 
+    /// # 引数
+    /// 
+    /// * `missile_id` - ミサイルのID
+    /// * `missile_distance` - ミサイルとの3次元距離（メートル）
+    /// * `missile_in_endgame` - ミサイルが終盤フェーズか
+    pub fn should_evade(&self, missile_id: &str, missile_distance: f64, missile_in_endgame: bool) -> bool {
+        let Some(profile) = &self.maneuver else {
+            return false;
+        };
+        self.status == AgentStatus::Active
+            && self.ballistic.is_none()
+            && self.active_maneuver.is_none()
+            && !self.evaded_missiles.iter().any(|id| id == missile_id)
+            && (missile_in_endgame || profile.trigger_range_m.is_some_and(|range| missile_distance <= range))
+    }
+
+    /// 回避機動を開始
+    /// 
+    /// # 引数
+    /// 
+    /// * `missile_id` - 脅威ミサイルのID
+    /// * `threat_position` - 脅威ミサイルの位置
+    /// * `rng` - 乱数生成器
+    pub fn start_maneuver<R: Rng + ?Sized>(&mut self, missile_id: &str, threat_position: &Position3D, rng: &mut R) {
+        let Some(profile) = self.maneuver else {
+            return;
+        };
+        self.active_maneuver = Some(profile.start(&self.position, &self.velocity, threat_position, rng));
+        self.evaded_missiles.push(missile_id.to_string());
+        info!(
+            target_id = %self.id,
+            target_group = %self.group_id,
+            position_x = self.position.x,
+            position_y = self.position.y,
+            position_z = self.position.z,
+            missile_id = %missile_id,
+            maneuver = ?profile.kind,
+            max_g = profile.max_g,
+            duration_s = profile.duration_s,
+            "TARGET_MANEUVER: ターゲットが回避機動を開始しました"
+        );
+    }
+
+    /// 実行中の回避機動を1ステップ進める
+    /// 
+    /// 継続時間が経過した場合は機動を終了し、経路点または目的地への直進に復帰します。
+    /// 
+    /// # 引数
+    /// 
+    /// * `dt` - 時間刻み（秒）
+    /// * `rng` - 乱数生成器
+    pub fn update_maneuver<R: Rng + ?Sized>(&mut self, dt: f64, rng: &mut R) {
+        let (Some(profile), Some(mut state)) = (self.maneuver, self.active_maneuver) else {
+            return;
+        };
+        if profile.is_finished(&state) {
+            self.active_maneuver = None;
+            self.update_velocity();
+            debug!(target_id = %self.id, "ターゲットが回避機動を終了しました");
+            return;
+        }
+        self.velocity = profile.apply(&mut state, &self.velocity, dt, rng);
+        self.active_maneuver = Some(state);
+    }
+
+    /// 現在の進行先に向けて速度ベクトルを設定
+    /// 
+    /// 経路点へは区間の速度で高度変化を含めて直進し、
+    /// 最終進入では目的地へターゲットの移動速度で向かいます。
+    fn update_velocity(&mut self) {
+        if let Some(waypoint) = self.waypoints.get(self.next_waypoint) {
+            let (dx, dy, dz) = (
+                waypoint.position.x - self.position.x,
+                waypoint.position.y - self.position.y,
+                waypoint.position.z - self.position.z,
+            );
+            let distance = (dx * dx + dy * dy + dz * dz).sqrt();
+            self.velocity = if distance > 0.0 {
+                Velocity3D::new(dx / distance * waypoint.speed, dy / distance * waypoint.speed, dz / distance * waypoint.speed)
+            } else {
+                Velocity3D::new(0.0, 0.0, 0.0)
+            };
+            return;
+        }
+
         // 目的地への方向ベクトルを計算して速度ベクトルを設定
         let direction = self.destination - self.position;
         let direction_magnitude = direction.magnitude();
         
         self.velocity = if direction_magnitude > 0.0 {
             Velocity3D::new(
-                (direction.x / direction_magnitude) * speed,
-                (direction.y / direction_magnitude) * speed,
-                (direction.z / direction_magnitude) * speed,
+                (direction.x / direction_magnitude) * self.speed,
+                (direction.y / direction_magnitude) * self.speed,
+                (direction.z / direction_magnitude) * self.speed,
             )
         } else {
             Velocity3D::new(0.0, 0.0, 0.0)
         };
     }
 
+    /// 経路点に向けて移動
+    /// 
+    /// このステップで経路点に到達する場合は経路点上に位置を合わせ、
+    /// 次の経路点（または目的地）へ向きを変えて、ステップの残り時間分を次の区間で移動します。
+    fn follow_route(&mut self, waypoint: Waypoint, dt: f64) {
+        let distance = self.position.distance_3d(&waypoint.position);
+        if distance <= waypoint.speed * dt {
+            self.position = waypoint.position;
+            self.next_waypoint += 1;
+            self.update_velocity();
+            let remaining = dt - distance / waypoint.speed;
+            if remaining > 0.0 {
+                self.move_agent(remaining);
+            }
+            return;
+        }
+
+        self.position.x += self.velocity.x * dt;
+        self.position.y += self.velocity.y * dt;
+        self.position.z = (self.position.z + self.velocity.z * dt).clamp(0.0, 5000.0);
+    }
+
+    /// 低高度飛行プロファイルに従って移動
+    /// 
+    /// 経路点または目的地へ水平に進み、高度は最終進入中の目的地までの
+    /// 水平距離に応じたプロファイル高度（経路点経由中は巡航高度）に合わせます。
+    fn fly_cruise(&mut self, profile: CruiseProfile, dt: f64) {
+        let waypoint = self.waypoints.get(self.next_waypoint).copied();
+        let (goal, speed) = match waypoint {
+            Some(waypoint) => (waypoint.position, waypoint.speed),
+            None => (self.destination, self.speed),
+        };
+        let (dx, dy) = (goal.x - self.position.x, goal.y - self.position.y);
+        let distance = (dx * dx + dy * dy).sqrt();
+        let (direction_x, direction_y) = if distance > 0.0 { (dx / distance, dy / distance) } else { (0.0, 0.0) };
+        
+        if waypoint.is_some() && distance <= speed * dt {
+            self.position.x = goal.x;
+            self.position.y = goal.y;
+            self.next_waypoint += 1;
+        } else {
+            self.position.x += direction_x * speed * dt;
+            self.position.y += direction_y * speed * dt;
+        }
+        
+        let previous_altitude = self.position.z;
+        self.position.z = if self.next_waypoint < self.waypoints.len() {
+            profile.cruise_altitude
+        } else {
+            profile.altitude_at(self.position.distance_xy(&self.destination))
+        };
+        self.velocity = Velocity3D::new(
+            direction_x * speed,
+            direction_y * speed,
+            if dt > 0.0 { (self.position.z - previous_altitude) / dt } else { 0.0 },
+        );
+    }
+
+    /// 高度プロファイルに従って目的地へ最終進入
+    /// 
+    /// 水平方向はターゲットの移動速度で目的地へ直進し、高度はダイブ角相当の
+    /// 勾配を上限としてプロファイル高度へ近づけます。
+    fn fly_altitude_profile(&mut self, profile: AltitudeProfile, dt: f64) {
+        let (dx, dy) = (self.destination.x - self.position.x, self.destination.y - self.position.y);
+        let distance = (dx * dx + dy * dy).sqrt();
+        let (direction_x, direction_y) = if distance > 0.0 { (dx / distance, dy / distance) } else { (0.0, 0.0) };
+        let step = (self.speed * dt).min(distance);
+        self.position.x += direction_x * step;
+        self.position.y += direction_y * step;
+        
+        let previous_altitude = self.position.z;
+        let max_change = step * profile.dive_angle_deg.to_radians().tan();
+        let desired_altitude = profile.altitude_at(distance - step);
+        self.position.z = previous_altitude + (desired_altitude - previous_altitude).clamp(-max_change, max_change);
+        self.velocity = Velocity3D::new(
+            direction_x * self.speed,
+            direction_y * self.speed,
+            if dt > 0.0 { (self.position.z - previous_altitude) / dt } else { 0.0 },
+        );
+    }
+
     /// スポーン判定
     /// 
     /// 現在時刻がスポーン時刻に達したかをチェックし、
@@ -201,8 +561,9 @@ impl Target {
     /// 
     /// * `current_time` - 現在のシミュレーション時刻（秒）
     pub fn check_spawn(&mut self, current_time: f64) {
-        if self.status == AgentStatus::Inactive && current_time >= self.spawn_time {
+        if !self.spawned && self.status == AgentStatus::Inactive && current_time >= self.spawn_time {
             self.status = AgentStatus::Active;
+            self.spawned = true;
         }
     }
 
@@ -210,21 +571,73 @@ impl Target {
     /// 到達予想時刻を計算（Tgo計算用）
     /// 
     /// ターゲットが現在位置から目的地に到達するまでの予想時間を計算します。
+    /// 未通過の経路点がある場合は、残りの経路（各区間の3次元距離と速度）を経由した時間となります。
+    /// 弾道ミサイル型は着弾までの残り時間です。
     /// 指揮所のターゲット優先度判定に使用されます。
     /// 
     /// # 戻り値
     /// 
     /// 到達予想時間（秒）、非アクティブまたは停止中の場合は無限大
     pub fn calculate_time_to_go(&self) -> f64 {
+        self.time_to_go_from(&self.position, None)
+    }
+
+    /// 指定した位置・速度から到達予想時刻を計算
+    /// 
+    /// 航跡の推定状態でTgoを求めるために、真の位置・速度の代わりに推定値を用いて
+    /// `calculate_time_to_go` と同じ経路・弾道のモデルで計算します。
+    /// 速度を指定した場合、現在の区間（次の経路点まで、または目的地まで）はその速さで、
+    /// 以降の区間は経路点の速度で移動するものとします。弾道ミサイル型は指定した高度と
+    /// 鉛直速度から、重力弾道で着弾点の高度に達するまでの時間です。
+    /// 
+    /// # 引数
+    /// 
+    /// * `position` - 現在位置（推定位置）
+    /// * `velocity` - 現在速度（推定速度、Noneの場合は経路点とターゲットの速度）
+    /// 
+    /// # 戻り値
+    /// 
+    /// 到達予想時間（秒）、非アクティブまたは停止中の場合は無限大
+    pub fn time_to_go_from(&self, position: &Position3D, velocity: Option<&Velocity3D>) -> f64 {
         if self.status != AgentStatus::Active {
             return f64::INFINITY;
         }
         
-        let distance_xy = self.position.distance_xy(&self.destination);
+        if let Some(trajectory) = &self.ballistic {
+            return match velocity {
+                // z + vz·t − g·t²/2 = z_impact の正の解
+                Some(velocity) => {
+                    let drop = (position.z - trajectory.impact.z).max(0.0);
+                    (velocity.z + (velocity.z.powi(2) + 2.0 * GRAVITY_MPS2 * drop).sqrt()) / GRAVITY_MPS2
+                }
+                None => trajectory.time_to_impact(),
+            };
+        }
+        
+        // 残りの経路点を経由する時間
+        let mut route_time = 0.0;
+        let mut leg_start = *position;
+        for (index, waypoint) in self.waypoints[self.next_waypoint.min(self.waypoints.len())..].iter().enumerate() {
+            let speed = match velocity {
+                Some(velocity) if index == 0 => velocity.magnitude(),
+                _ => waypoint.speed,
+            };
+            if speed <= 0.0 {
+                return f64::INFINITY;
+            }
+            route_time += leg_start.distance_3d(&waypoint.position) / speed;
+            leg_start = waypoint.position;
+        }
+        
+        let distance_xy = leg_start.distance_xy(&self.destination);
         let remaining_distance = (distance_xy - self.arrival_radius).max(0.0);
+        let speed = match velocity {
+            Some(velocity) if self.next_waypoint >= self.waypoints.len() => velocity.magnitude_xy(),
+            _ => self.speed,
+        };
         
-        if self.speed > 0.0 {
-            remaining_distance / self.speed
+        if speed > 0.0 {
+            route_time + remaining_distance / speed
         } else {
             f64::INFINITY
         }
@@ -284,6 +697,40 @@ impl IAgent for Target {
 impl IMovable for Target {
     fn move_agent(&mut self, dt: f64) {
         if self.status == AgentStatus::Active {
+            // 弾道ミサイル型は重力弾道に沿って移動（着弾点で停止）
+            if let Some(trajectory) = &mut self.ballistic {
+                trajectory.elapsed = (trajectory.elapsed + dt).min(trajectory.flight_time);
+                self.position = trajectory.position_at(trajectory.elapsed);
+                self.velocity = trajectory.velocity_at(trajectory.elapsed);
+                return;
+            }
+            
+            // 回避機動中は機動後の速度で移動（高度変化を含む）
+            if self.active_maneuver.is_some() {
+                self.position.x += self.velocity.x * dt;
+                self.position.y += self.velocity.y * dt;
+                self.position.z = (self.position.z + self.velocity.z * dt).clamp(0.0, 5000.0);
+                return;
+            }
+            
+            // 巡航ミサイル型は低高度飛行プロファイルに従って移動
+            if let Some(profile) = self.cruise {
+                self.fly_cruise(profile, dt);
+                return;
+            }
+            
+            // 経路点の追従
+            if let Some(waypoint) = self.waypoints.get(self.next_waypoint).copied() {
+                self.follow_route(waypoint, dt);
+                return;
+            }
+            
+            // 高度プロファイルに従った最終進入
+            if let Some(profile) = self.altitude_profile {
+                self.fly_altitude_profile(profile, dt);
+                return;
+            }
+            
             // 等速直線運動
             self.position = self.position + Position3D::new(
                 self.velocity.x * dt,
@@ -313,10 +760,30 @@ impl IMovable for Target {
     }
 }
 
+/// 敵グループの編隊形状
+/// 
+/// 方位角（`heading_deg`）は編隊の進行方向で、`angle_reference` の規約
+/// （+X軸を0度とし反時計回りを正）に従います。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Formation {
+    /// 中心1機＋同心円リング配置（`ring_spacing` / `start_angle` / `ring_half_offset` を使用）
+    Rings,
+    /// 横一列（中心を挟んで進行方向に垂直に並ぶ）
+    LineAbreast { spacing: f64, heading_deg: f64 },
+    /// 縦一列（先頭を中心に置き後方へ並ぶ）
+    Column { spacing: f64, heading_deg: f64 },
+    /// V字（先頭を中心に置き左右後方へ交互に並ぶ）
+    Wedge { spacing: f64, heading_deg: f64 },
+    /// 格子（`columns` 列で中心周りに並ぶ）
+    Box { spacing: f64, heading_deg: f64, columns: u32 },
+    /// 中心から半径内へのランダム散開（シード値で再現可能）
+    Scatter { radius: f64, seed: u64 },
+}
+
 /// 敵グループの配置パターンを生成するヘルパー構造体
 /// 
-/// 複数の敵ターゲットを同心円リング状に配置し、同じパラメータで
-/// 一括生成するためのユーティリティです。リング配置では中心に1個、
+/// 複数の敵ターゲットを編隊形状に従って配置し、同じパラメータで
+/// 一括生成するためのユーティリティです。既定のリング配置では中心に1個、
 /// その周囲に同心円状に配置し、外側リングでは半角オフセットが可能です。
 pub struct TargetGroup {
     /// グループの一意識別子
@@ -331,29 +798,121 @@ pub struct TargetGroup {
     pub start_angle: f64,
     /// 外側リングで半角オフセットを使用するか
     pub ring_half_offset: bool,
+    /// 編隊形状
+    pub formation: Formation,
     /// グループ内ターゲットの耐久値
     pub endurance: u32,
-    /// グループのスポーン時刻（秒）
+    /// グループのスポーン時刻（秒、先頭機の出現時刻）
     pub spawn_time: f64,
+    /// グループ内の各機の出現間隔（秒）
+    pub spawn_interval: f64,
+    /// 出現時刻・速度・出現位置のばらつき
+    pub spawn_jitter: Option<SpawnJitter>,
+    /// 出現可能範囲（xmin, xmax, ymin, ymax、範囲外の機は境界に寄せて出現）
+    pub spawn_bounds: Option<(f64, f64, f64, f64)>,
     /// グループ内ターゲットの移動速度（m/s）
     pub speed: f64,
-    /// グループの目的地（指揮所位置）
+    /// グループの目的地（防護対象の位置）
     pub destination: Position3D,
     /// 目的地への到達判定範囲（メートル）
     pub arrival_radius: f64,
+    /// 攻撃目標の防護対象ID
+    pub asset_id: String,
+    /// グループ中心が経由する経路点（各ターゲットは配置オフセットを保って追従）
+    pub waypoints: Vec<Waypoint>,
+    /// グループ内ターゲットの回避機動プロファイル
+    pub maneuver: Option<ManeuverProfile>,
+    /// 弾道ミサイル型の場合の着弾点と弾道形状（各ターゲットは配置位置から発射）
+    pub ballistic: Option<(Position3D, BallisticShape)>,
+    /// 巡航ミサイル型の場合の低高度飛行プロファイル
+    pub cruise: Option<CruiseProfile>,
+    /// グループ内ターゲットの最終進入時の高度プロファイル
+    pub altitude_profile: Option<AltitudeProfile>,
+    /// グループ内ターゲットのレーダー反射断面積（平方メートル）
+    pub rcs_m2: f64,
+    /// グループ内のデコイの割合（0.0〜1.0、グループ内に均等に配置）
+    pub decoy_ratio: f64,
 }
 
 impl TargetGroup {
     /// グループ内のターゲット配置位置を計算
     /// 
-    /// 同心円リング配置アルゴリズムに基づいて、指定された数の
-    /// ターゲットの配置位置を計算します。中心に1個、その外側に各リングに
-    /// 等角度間隔で配置します。
+    /// 編隊形状に従って、指定された数のターゲットの配置位置を計算します。
     /// 
     /// # 戻り値
     /// 
     /// 計算された各ターゲットの配置位置のベクター
     pub fn generate_positions(&self) -> Vec<Position3D> {
+        let count = self.count as usize;
+        match self.formation {
+            Formation::Rings => self.generate_ring_positions(),
+            Formation::LineAbreast { spacing, heading_deg } => {
+                let half_width = (count as f64 - 1.0) / 2.0;
+                (0..count)
+                    .map(|i| self.formation_position(heading_deg, 0.0, (half_width - i as f64) * spacing))
+                    .collect()
+            }
+            Formation::Column { spacing, heading_deg } => (0..count)
+                .map(|i| self.formation_position(heading_deg, -(i as f64) * spacing, 0.0))
+                .collect(),
+            Formation::Wedge { spacing, heading_deg } => (0..count)
+                .map(|i| {
+                    // 0: 先頭, 1: 左1, 2: 右1, 3: 左2, ...
+                    let rank = i.div_ceil(2) as f64;
+                    let side = if i % 2 == 1 { 1.0 } else { -1.0 };
+                    self.formation_position(heading_deg, -rank * spacing, side * rank * spacing)
+                })
+                .collect(),
+            Formation::Box { spacing, heading_deg, columns } => {
+                let columns = (columns.max(1) as usize).min(count.max(1));
+                let rows = count.div_ceil(columns);
+                let half_width = (columns as f64 - 1.0) / 2.0;
+                let half_depth = (rows as f64 - 1.0) / 2.0;
+                (0..count)
+                    .map(|i| {
+                        let (row, column) = ((i / columns) as f64, (i % columns) as f64);
+                        self.formation_position(heading_deg, (half_depth - row) * spacing, (half_width - column) * spacing)
+                    })
+                    .collect()
+            }
+            Formation::Scatter { radius, seed } => {
+                let mut rng = ChaCha8Rng::seed_from_u64(seed);
+                (0..count)
+                    .map(|_| {
+                        // 円内一様分布
+                        let distance = radius * rng.gen_range(0.0f64..1.0).sqrt();
+                        let angle = rng.gen_range(0.0..std::f64::consts::TAU);
+                        Position3D::new(
+                            self.center_position.x + distance * angle.cos(),
+                            self.center_position.y + distance * angle.sin(),
+                            self.center_position.z,
+                        )
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// 編隊座標（前方・左方のオフセット）から配置位置を計算
+    /// 
+    /// # 引数
+    /// 
+    /// * `heading_deg` - 編隊の進行方向（+X基準・反時計回り、度）
+    /// * `forward` - 進行方向へのオフセット（メートル）
+    /// * `left` - 進行方向左へのオフセット（メートル）
+    fn formation_position(&self, heading_deg: f64, forward: f64, left: f64) -> Position3D {
+        let (sin, cos) = heading_deg.to_radians().sin_cos();
+        Position3D::new(
+            self.center_position.x + forward * cos - left * sin,
+            self.center_position.y + forward * sin + left * cos,
+            self.center_position.z,
+        )
+    }
+
+    /// 同心円リング配置の位置を計算
+    /// 
+    /// 中心に1個、その外側に各リングに等角度間隔で配置します。
+    fn generate_ring_positions(&self) -> Vec<Position3D> {
         let mut positions = Vec::new();
         let mut remaining_count = self.count as usize;
         let mut ring_index = 1;
@@ -408,7 +967,9 @@ impl TargetGroup {
     /// グループ内の全ターゲットを生成
     /// 
     /// 配置位置を計算し、各位置にターゲットを生成します。
-    /// 全てのターゲットには同じグループパラメータが適用されます。
+    /// 全てのターゲットには同じグループパラメータが適用され、
+    /// 出現時刻は出現間隔ずつずらし、ばらつきが設定されていれば
+    /// 出現時刻・速度・出現位置に乱数を加えます。
     /// 
     /// # 戻り値
     /// 
@@ -416,12 +977,34 @@ impl TargetGroup {
     pub fn generate_targets(&self) -> Vec<Target> {
         let positions = self.generate_positions();
         let mut targets = Vec::new();
+        let mut jitter_rng = self.spawn_jitter.map(|jitter| ChaCha8Rng::seed_from_u64(jitter.seed));
 
         for (index, position) in positions.iter().enumerate() {
+            let mut position = *position;
+            let mut spawn_time = self.spawn_time + index as f64 * self.spawn_interval;
+            let mut speed = self.speed;
+            if let (Some(jitter), Some(rng)) = (self.spawn_jitter, jitter_rng.as_mut()) {
+                spawn_time = (spawn_time + jitter.time * rng.gen_range(-1.0..1.0)).max(0.0);
+                speed += jitter.speed * rng.gen_range(-1.0..1.0);
+                let distance = jitter.position * rng.gen_range(0.0f64..1.0).sqrt();
+                let angle = rng.gen_range(0.0..std::f64::consts::TAU);
+                position.x += distance * angle.cos();
+                position.y += distance * angle.sin();
+            }
+            
             let target_id = format!("{}_T{:03}", self.id, index + 1);
+            if let Some((xmin, xmax, ymin, ymax)) = self.spawn_bounds {
+                let (x, y) = (position.x.clamp(xmin, xmax), position.y.clamp(ymin, ymax));
+                if (x, y) != (position.x, position.y) {
+                    warn!(target_id = %target_id, position_x = position.x, position_y = position.y,
+                          "出現位置が敵出現範囲（spawn_rect_xy）外のため範囲内に寄せます");
+                    position.x = x;
+                    position.y = y;
+                }
+            }
             let mut target = Target::new(
                 target_id,
-                *position,
+                position,
                 self.destination,
                 self.id.clone(),
             );
@@ -430,13 +1013,276 @@ impl TargetGroup {
             target.set_parameters(
                 self.arrival_radius,
                 self.endurance,
-                self.spawn_time,
-                self.speed,
+                spawn_time,
+                speed,
             );
             
+            target.asset_id = self.asset_id.clone();
+            target.maneuver = self.maneuver;
+            target.altitude_profile = self.altitude_profile;
+            target.rcs_m2 = self.rcs_m2;
+            // 割合に応じて floor(count × decoy_ratio) 機を均等な間隔でデコイとする
+            target.is_decoy = ((index + 1) as f64 * self.decoy_ratio).floor() > (index as f64 * self.decoy_ratio).floor();
+            
+            if let Some((impact, shape)) = self.ballistic
+                && !target.set_ballistic(impact, shape)
+            {
+                warn!(target_id = %target.id, "弾道が成立しないため通常の飛行に切り替えます");
+            }
+            
+            // 経路点は編隊内の水平オフセットを保つように平行移動
+            if !self.waypoints.is_empty() {
+                let (offset_x, offset_y) = (position.x - self.center_position.x, position.y - self.center_position.y);
+                target.set_route(
+                    self.waypoints
+                        .iter()
+                        .map(|waypoint| Waypoint {
+                            position: Position3D {
+                                x: waypoint.position.x + offset_x,
+                                y: waypoint.position.y + offset_y,
+                                z: waypoint.position.z,
+                            },
+                            speed: waypoint.speed,
+                        })
+                        .collect(),
+                );
+            }
+            
+            if let Some(profile) = self.cruise {
+                target.set_cruise(profile);
+            }
+            
             targets.push(target);
         }
 
         targets
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn routed_target() -> Target {
+        let mut target = Target::new(
+            "T001".to_string(),
+            Position3D::new(0.0, 0.0, 3000.0),
+            Position3D::new(20_000.0, 0.0, 0.0),
+            "G001".to_string(),
+        );
+        target.set_parameters(1000.0, 1, 0.0, 100.0);
+        target.set_route(vec![
+            Waypoint { position: Position3D::new(0.0, 10_000.0, 1000.0), speed: 200.0 },
+            Waypoint { position: Position3D::new(20_000.0, 10_000.0, 1000.0), speed: 100.0 },
+        ]);
+        target.check_spawn(0.0);
+        target
+    }
+
+    fn group_with(formation: Formation, count: u32) -> TargetGroup {
+        TargetGroup {
+            id: "G001".to_string(),
+            center_position: Position3D::new(1000.0, 2000.0, 3000.0),
+            count,
+            ring_spacing: 500.0,
+            start_angle: 0.0,
+            ring_half_offset: false,
+            formation,
+            endurance: 1,
+            spawn_time: 0.0,
+            spawn_interval: 0.0,
+            spawn_jitter: None,
+            spawn_bounds: None,
+            speed: 200.0,
+            destination: Position3D::new(0.0, 0.0, 0.0),
+            arrival_radius: 1000.0,
+            asset_id: "CP001".to_string(),
+            waypoints: Vec::new(),
+            maneuver: None,
+            ballistic: None,
+            cruise: None,
+            altitude_profile: None,
+            rcs_m2: REFERENCE_RCS_M2,
+            decoy_ratio: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_formation_layouts() {
+        // 進行方向+Y: 横一列はX方向に並ぶ
+        let line = group_with(Formation::LineAbreast { spacing: 100.0, heading_deg: 90.0 }, 3).generate_positions();
+        assert!((line[0].x - 900.0).abs() < 1e-9 && (line[2].x - 1100.0).abs() < 1e-9);
+        assert!(line.iter().all(|p| (p.y - 2000.0).abs() < 1e-9));
+
+        // 進行方向+X: V字は先頭が中心、2番機は左後方（+Y）
+        let wedge = group_with(Formation::Wedge { spacing: 100.0, heading_deg: 0.0 }, 3).generate_positions();
+        assert_eq!(wedge[0], Position3D::new(1000.0, 2000.0, 3000.0));
+        assert!((wedge[1].x - 900.0).abs() < 1e-9 && (wedge[1].y - 2100.0).abs() < 1e-9);
+        assert!((wedge[2].y - 1900.0).abs() < 1e-9);
+
+        let boxed = group_with(Formation::Box { spacing: 100.0, heading_deg: 0.0, columns: 2 }, 4).generate_positions();
+        let centroid_x = boxed.iter().map(|p| p.x).sum::<f64>() / 4.0;
+        assert!((centroid_x - 1000.0).abs() < 1e-9);
+
+        let scatter = Formation::Scatter { radius: 5000.0, seed: 42 };
+        let first = group_with(scatter, 10).generate_positions();
+        assert_eq!(first, group_with(scatter, 10).generate_positions());
+        assert!(first.iter().all(|p| p.distance_xy(&Position3D::new(1000.0, 2000.0, 0.0)) <= 5000.0));
+    }
+
+    #[test]
+    fn test_time_to_go_follows_route() {
+        let mut target = routed_target();
+        // 高度3000mから1000mへ降下する約10.2km@200m/s + 20km@100m/s + (10km - 1km)@100m/s
+        let first_leg_time = Position3D::new(0.0, 0.0, 3000.0).distance_3d(&Position3D::new(0.0, 10_000.0, 1000.0)) / 200.0;
+        assert!((target.calculate_time_to_go() - (first_leg_time + 200.0 + 90.0)).abs() < 1e-9);
+
+        // 経路どおりに飛行した時間と一致
+        let mut elapsed = 0.0;
+        while target.next_waypoint < 1 {
+            target.move_agent(0.1);
+            elapsed += 0.1;
+        }
+        assert!((elapsed - first_leg_time).abs() < 0.1, "{}", elapsed);
+    }
+
+    #[test]
+    fn test_route_is_followed_in_order() {
+        let mut target = routed_target();
+        for _ in 0..60 {
+            target.move_agent(1.0);
+        }
+        assert_eq!(target.next_waypoint, 1);
+        assert!((target.position.z - 1000.0).abs() < 1e-9);
+        assert!(target.velocity.x > 0.0 && target.velocity.y.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_route_carries_leftover_step_into_next_leg() {
+        let mut target = routed_target();
+        let first_leg_time = Position3D::new(0.0, 0.0, 3000.0).distance_3d(&Position3D::new(0.0, 10_000.0, 1000.0)) / 200.0;
+        // 経路点への到達がステップの途中になる時間刻み
+        for _ in 0..80 {
+            target.move_agent(0.7);
+        }
+        let elapsed = 80.0 * 0.7;
+        assert_eq!(target.next_waypoint, 1);
+        assert!((target.position.x - 100.0 * (elapsed - first_leg_time)).abs() < 1e-6, "{}", target.position.x);
+        assert!((target.position.y - 10_000.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_maneuver_once_per_missile_then_straight_flight() {
+        let mut target = Target::new(
+            "T001".to_string(),
+            Position3D::new(0.0, 0.0, 3000.0),
+            Position3D::new(100_000.0, 0.0, 3000.0),
+            "G001".to_string(),
+        );
+        target.set_parameters(1000.0, 1, 0.0, 200.0);
+        target.maneuver = Some(ManeuverProfile {
+            kind: crate::models::maneuver::ManeuverType::BreakTurn,
+            max_g: 5.0,
+            duration_s: 3.0,
+            period_s: 1.0,
+            trigger_range_m: None,
+        });
+        target.check_spawn(0.0);
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let missile_position = Position3D::new(5000.0, 500.0, 3000.0);
+
+        // エンジンと同じ順序（機動更新 → 移動 → 脅威判定）で、同じミサイルが終盤フェーズのまま
+        let mut maneuvers = 0;
+        let mut straight_velocities = Vec::new();
+        for _ in 0..100 {
+            target.update_maneuver(0.1, &mut rng);
+            target.move_agent(0.1);
+            if target.active_maneuver.is_none() && maneuvers > 0 {
+                straight_velocities.push(target.velocity);
+            }
+            if target.should_evade("M001", 1000.0, true) {
+                target.start_maneuver("M001", &missile_position, &mut rng);
+                maneuvers += 1;
+            }
+        }
+
+        // 機動終了後は再機動せず、目的地へ直進する
+        assert_eq!(maneuvers, 1);
+        assert!(straight_velocities.len() > 50);
+        assert!(straight_velocities.iter().all(|velocity| *velocity == straight_velocities[0]));
+        // 別のミサイルに対しては再び機動する
+        assert!(target.should_evade("M002", 1000.0, true));
+    }
+
+    #[test]
+    fn test_staggered_spawn_with_jitter() {
+        let mut group = group_with(Formation::Rings, 4);
+        group.spawn_interval = 10.0;
+        group.spawn_jitter = Some(SpawnJitter { time: 1.0, speed: 20.0, position: 500.0, seed: 7 });
+        let targets = group.generate_targets();
+        for (index, target) in targets.iter().enumerate() {
+            assert!((target.spawn_time - index as f64 * 10.0).abs() <= 1.0);
+            assert!((target.speed - 200.0).abs() <= 20.0);
+        }
+        assert!(targets[0].position.distance_xy(&group.center_position) <= 500.0);
+        // 同じシードでは同じばらつき
+        let again = group.generate_targets();
+        assert!(targets.iter().zip(&again).all(|(a, b)| a.spawn_time == b.spawn_time && a.position == b.position));
+    }
+
+    #[test]
+    fn test_members_outside_spawn_bounds_are_clamped() {
+        // 中心は範囲内だが、横一列の両端とばらつきで範囲外に出る
+        let mut group = group_with(Formation::LineAbreast { spacing: 1000.0, heading_deg: 90.0 }, 5);
+        group.spawn_jitter = Some(SpawnJitter { time: 0.0, speed: 0.0, position: 800.0, seed: 3 });
+        let unbounded = group.generate_targets();
+        group.spawn_bounds = Some((0.0, 2000.0, 1500.0, 2500.0));
+        let bounded = group.generate_targets();
+
+        assert!(unbounded.iter().any(|t| !(0.0..=2000.0).contains(&t.position.x)));
+        for (free, clamped) in unbounded.iter().zip(&bounded) {
+            assert!((0.0..=2000.0).contains(&clamped.position.x) && (1500.0..=2500.0).contains(&clamped.position.y));
+            assert_eq!(clamped.position.x, free.position.x.clamp(0.0, 2000.0));
+            assert_eq!(clamped.position.y, free.position.y.clamp(1500.0, 2500.0));
+        }
+    }
+
+    #[test]
+    fn test_altitude_profile_terminal_dive() {
+        let mut group = group_with(Formation::Rings, 1);
+        group.center_position = Position3D::new(30_000.0, 0.0, 3000.0);
+        group.altitude_profile = Some(AltitudeProfile { cruise_altitude: 3000.0, descent_start: 15_000.0, dive_angle_deg: 30.0 });
+        let mut target = group.generate_targets().remove(0);
+        target.status = AgentStatus::Active;
+
+        // 降下開始距離までは巡航高度を保つ
+        for _ in 0..70 {
+            target.move_agent(1.0);
+        }
+        assert!((target.position.x - 16_000.0).abs() < 1e-6 && (target.position.z - 3000.0).abs() < 1e-9);
+        // 降下開始後はダイブ角で降下
+        for _ in 0..10 {
+            target.move_agent(1.0);
+        }
+        let expected = 3000.0 - 1000.0 * 30f64.to_radians().tan();
+        assert!((target.position.z - expected).abs() < 1e-6);
+        assert!(target.velocity.z < 0.0);
+    }
+
+    #[test]
+    fn test_decoy_arrival_is_not_breakthrough() {
+        let mut group = group_with(Formation::Rings, 4);
+        group.decoy_ratio = 0.5;
+        let mut targets = group.generate_targets();
+        let decoys: Vec<bool> = targets.iter().map(|t| t.is_decoy).collect();
+        assert_eq!(decoys, vec![false, true, false, true]);
+
+        for target in &mut targets[..2] {
+            target.status = AgentStatus::Active;
+            target.position = target.destination;
+            target.check_arrival();
+        }
+        assert_eq!(targets[0].status, AgentStatus::Reached);
+        assert_eq!(targets[1].status, AgentStatus::Inactive);
+    }
+}
@@ -0,0 +1,413 @@
+//! # Tracking モジュール
+//!
+//! センサーの雑音付き観測と、ターゲットごとの等速直線運動カルマンフィルタによる航跡推定を定義します。
+//!
+//! センサーは距離・方位角・仰角を正規分布の雑音付きで観測し、直交座標の観測位置と
+//! 観測誤差共分散（極座標の誤差をヤコビアンで直交座標に変換）を指揮所に渡します。
+//! 指揮所はターゲットごとの航跡を観測で更新し、推定した位置・速度からTgoを算出します。
+//!
+//! ## 航跡フィルタ
+//!
+//! - **状態**: 位置（x, y, z）と速度（vx, vy, vz）
+//! - **運動モデル**: 等速直線運動（加速度を白色雑音とするプロセス雑音 `process_noise` m/s²）
+//! - **初期化**: 初回観測の位置、速度0（標準偏差 `INITIAL_VELOCITY_SIGMA_MPS`）
+//!
+//! 共分散は位置・速度の3×3ブロックで保持します。
+
+use rand::Rng;
+use crate::models::common::{Position3D, Velocity3D};
+
+/// 航跡初期化時の速度の標準偏差（m/s）
+pub const INITIAL_VELOCITY_SIGMA_MPS: f64 = 500.0;
+
+/// 航跡のプロセス雑音（加速度の標準偏差、m/s²）の既定値
+pub const DEFAULT_PROCESS_NOISE_MPS2: f64 = 10.0;
+
+type Matrix3 = [[f64; 3]; 3];
+
+const IDENTITY: Matrix3 = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+/// センサーの観測雑音（標準偏差）
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MeasurementNoise {
+    /// 距離の標準偏差（メートル）
+    pub range_sigma: f64,
+    /// 方位角の標準偏差（度）
+    pub azimuth_sigma_deg: f64,
+    /// 仰角の標準偏差（度）
+    pub elevation_sigma_deg: f64,
+}
+
+impl MeasurementNoise {
+    /// ターゲットの真位置を雑音付きで観測
+    ///
+    /// # 引数
+    ///
+    /// * `rng` - 乱数生成器
+    /// * `sensor_position` - センサー位置
+    /// * `true_position` - ターゲットの真位置
+    /// * `time` - 観測時刻（秒）
+    ///
+    /// # 戻り値
+    ///
+    /// 直交座標の観測位置と観測誤差共分散
+    pub fn measure<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        sensor_position: &Position3D,
+        true_position: &Position3D,
+        time: f64,
+    ) -> Measurement {
+        let dx = true_position.x - sensor_position.x;
+        let dy = true_position.y - sensor_position.y;
+        let dz = true_position.z - sensor_position.z;
+        let range = (dx * dx + dy * dy + dz * dz).sqrt() + self.range_sigma * standard_normal(rng);
+        let azimuth = dy.atan2(dx) + self.azimuth_sigma_deg.to_radians() * standard_normal(rng);
+        let elevation = dz.atan2(dx.hypot(dy)) + self.elevation_sigma_deg.to_radians() * standard_normal(rng);
+
+        let (sin_az, cos_az) = azimuth.sin_cos();
+        let (sin_el, cos_el) = elevation.sin_cos();
+        let position = [
+            sensor_position.x + range * cos_el * cos_az,
+            sensor_position.y + range * cos_el * sin_az,
+            sensor_position.z + range * sin_el,
+        ];
+
+        // 極座標（距離, 方位角, 仰角）から直交座標へのヤコビアン
+        let jacobian = [
+            [cos_el * cos_az, -range * cos_el * sin_az, -range * sin_el * cos_az],
+            [cos_el * sin_az, range * cos_el * cos_az, -range * sin_el * sin_az],
+            [sin_el, 0.0, range * cos_el],
+        ];
+        let variances = [
+            self.range_sigma.powi(2),
+            self.azimuth_sigma_deg.to_radians().powi(2),
+            self.elevation_sigma_deg.to_radians().powi(2),
+        ];
+        let mut covariance = [[0.0; 3]; 3];
+        for (i, row) in covariance.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = (0..3).map(|k| jacobian[i][k] * variances[k] * jacobian[j][k]).sum();
+            }
+        }
+
+        Measurement { time, position, covariance }
+    }
+}
+
+/// 直交座標の観測
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Measurement {
+    /// 観測時刻（秒）
+    pub time: f64,
+    /// 観測位置（x, y, z）
+    pub position: [f64; 3],
+    /// 観測誤差共分散
+    pub covariance: [[f64; 3]; 3],
+}
+
+/// ターゲットの航跡（等速直線運動カルマンフィルタ）
+#[derive(Debug, Clone, PartialEq)]
+pub struct Track {
+    /// 推定位置（x, y, z）
+    pub position: [f64; 3],
+    /// 推定速度（vx, vy, vz）
+    pub velocity: [f64; 3],
+    /// 位置の誤差共分散
+    position_covariance: Matrix3,
+    /// 位置と速度の相互共分散
+    cross_covariance: Matrix3,
+    /// 速度の誤差共分散
+    velocity_covariance: Matrix3,
+    /// 推定状態の時刻（秒）
+    pub time: f64,
+    /// 観測による更新回数
+    pub updates: u32,
+}
+
+impl Track {
+    /// 初回観測から航跡を開始
+    pub fn new(measurement: &Measurement) -> Self {
+        Self {
+            position: measurement.position,
+            velocity: [0.0; 3],
+            position_covariance: measurement.covariance,
+            cross_covariance: [[0.0; 3]; 3],
+            velocity_covariance: scale(&IDENTITY, INITIAL_VELOCITY_SIGMA_MPS.powi(2)),
+            time: measurement.time,
+            updates: 1,
+        }
+    }
+
+    /// 指定時刻まで推定状態を外挿
+    ///
+    /// # 引数
+    ///
+    /// * `time` - 外挿先の時刻（秒）
+    /// * `process_noise` - 加速度の標準偏差（m/s²）
+    pub fn predict(&mut self, time: f64, process_noise: f64) {
+        if time > self.time {
+            self.propagate(time, process_noise);
+        }
+    }
+
+    /// 指定時刻へ推定状態を遷移（過去の時刻への遡りを含む）
+    fn propagate(&mut self, time: f64, process_noise: f64) {
+        let dt = time - self.time;
+        for axis in 0..3 {
+            self.position[axis] += self.velocity[axis] * dt;
+        }
+
+        let q = process_noise.powi(2);
+        let pp = &self.position_covariance;
+        let pv = &self.cross_covariance;
+        let vv = &self.velocity_covariance;
+        let position_covariance = add(
+            &add(pp, &scale(&add(pv, &transpose(pv)), dt)),
+            &add(&scale(vv, dt * dt), &scale(&IDENTITY, q * dt.powi(4) / 4.0)),
+        );
+        let cross_covariance = add(&add(pv, &scale(vv, dt)), &scale(&IDENTITY, q * dt.powi(3) / 2.0));
+        let velocity_covariance = add(vv, &scale(&IDENTITY, q * dt * dt));
+
+        self.position_covariance = position_covariance;
+        self.cross_covariance = cross_covariance;
+        self.velocity_covariance = velocity_covariance;
+        self.time = time;
+    }
+
+    /// 観測で推定状態を更新（観測時刻への外挿を含む）
+    ///
+    /// # 引数
+    ///
+    /// * `measurement` - 観測
+    /// * `process_noise` - 加速度の標準偏差（m/s²）
+    pub fn update(&mut self, measurement: &Measurement, process_noise: f64) {
+        self.predict(measurement.time, process_noise);
+
+        let Some(innovation_inverse) = invert(&add(&self.position_covariance, &measurement.covariance)) else {
+            return;
+        };
+        let position_gain = multiply(&self.position_covariance, &innovation_inverse);
+        let velocity_gain = multiply(&transpose(&self.cross_covariance), &innovation_inverse);
+        let innovation: [f64; 3] = std::array::from_fn(|axis| measurement.position[axis] - self.position[axis]);
+        for axis in 0..3 {
+            self.position[axis] += (0..3).map(|k| position_gain[axis][k] * innovation[k]).sum::<f64>();
+            self.velocity[axis] += (0..3).map(|k| velocity_gain[axis][k] * innovation[k]).sum::<f64>();
+        }
+
+        let pp = self.position_covariance;
+        let pv = self.cross_covariance;
+        self.position_covariance = sub(&pp, &multiply(&position_gain, &pp));
+        self.cross_covariance = sub(&pv, &multiply(&position_gain, &pv));
+        self.velocity_covariance = sub(&self.velocity_covariance, &multiply(&velocity_gain, &pv));
+        // 丸め誤差で対称性が崩れると観測間隔が長い場合に発散するため、対称化する
+        self.position_covariance = symmetrize(&self.position_covariance);
+        self.velocity_covariance = symmetrize(&self.velocity_covariance);
+        self.updates += 1;
+    }
+
+    /// 観測との統計的距離（観測時刻に外挿した推定位置とのマハラノビス距離の2乗）
+    ///
+    /// 観測時刻が推定状態より古い場合（報告遅延の異なるセンサーの観測）は観測時刻まで遡って評価します。
+    ///
+    /// # 引数
+    ///
+    /// * `measurement` - 観測
+    /// * `process_noise` - 加速度の標準偏差（m/s²）
+    ///
+    /// # 戻り値
+    ///
+    /// マハラノビス距離の2乗、共分散が特異な場合はNone
+    pub fn gating_distance(&self, measurement: &Measurement, process_noise: f64) -> Option<f64> {
+        let mut predicted = self.clone();
+        predicted.propagate(measurement.time, process_noise);
+        let innovation_inverse = invert(&add(&predicted.position_covariance, &measurement.covariance))?;
+        let innovation: [f64; 3] = std::array::from_fn(|axis| measurement.position[axis] - predicted.position[axis]);
+        Some((0..3).map(|i| (0..3).map(|j| innovation[i] * innovation_inverse[i][j] * innovation[j]).sum::<f64>()).sum())
+    }
+
+    /// 別の航跡との統計的距離（新しい方の時刻に外挿した推定位置の差のマハラノビス距離の2乗）
+    ///
+    /// # 引数
+    ///
+    /// * `other` - 比較する航跡
+    /// * `process_noise` - 加速度の標準偏差（m/s²）
+    ///
+    /// # 戻り値
+    ///
+    /// マハラノビス距離の2乗、共分散が特異な場合はNone
+    pub fn track_distance(&self, other: &Track, process_noise: f64) -> Option<f64> {
+        let time = self.time.max(other.time);
+        let mut first = self.clone();
+        let mut second = other.clone();
+        first.predict(time, process_noise);
+        second.predict(time, process_noise);
+        let difference_inverse = invert(&add(&first.position_covariance, &second.position_covariance))?;
+        let difference: [f64; 3] = std::array::from_fn(|axis| first.position[axis] - second.position[axis]);
+        Some((0..3).map(|i| (0..3).map(|j| difference[i] * difference_inverse[i][j] * difference[j]).sum::<f64>()).sum())
+    }
+
+    /// 推定位置（弾道ミサイル型の航跡のため高度制限なし）
+    pub fn estimated_position(&self) -> Position3D {
+        Position3D { x: self.position[0], y: self.position[1], z: self.position[2] }
+    }
+
+    /// 推定速度
+    pub fn estimated_velocity(&self) -> Velocity3D {
+        Velocity3D::new(self.velocity[0], self.velocity[1], self.velocity[2])
+    }
+
+    /// 位置推定の標準偏差（共分散のトレースの平方根、メートル）
+    pub fn position_sigma(&self) -> f64 {
+        (0..3).map(|axis| self.position_covariance[axis][axis]).sum::<f64>().sqrt()
+    }
+}
+
+/// 航跡推定誤差の集計
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TrackErrorStats {
+    /// 集計したサンプル数（航跡×ステップ）
+    pub samples: usize,
+    sum_squared_position_error: f64,
+    sum_squared_velocity_error: f64,
+    sum_position_sigma: f64,
+    /// 位置誤差の最大値（メートル）
+    pub max_position_error: f64,
+}
+
+impl TrackErrorStats {
+    /// 航跡と真値の誤差を記録
+    pub fn record(&mut self, track: &Track, true_position: &Position3D, true_velocity: &Velocity3D) {
+        let position_error = track.estimated_position().distance_3d(true_position);
+        let velocity = track.estimated_velocity();
+        let velocity_error = Velocity3D::new(
+            velocity.x - true_velocity.x,
+            velocity.y - true_velocity.y,
+            velocity.z - true_velocity.z,
+        ).magnitude();
+        self.samples += 1;
+        self.sum_squared_position_error += position_error.powi(2);
+        self.sum_squared_velocity_error += velocity_error.powi(2);
+        self.sum_position_sigma += track.position_sigma();
+        self.max_position_error = self.max_position_error.max(position_error);
+    }
+
+    /// 位置誤差のRMS（メートル）
+    pub fn rms_position_error(&self) -> f64 {
+        (self.sum_squared_position_error / self.samples.max(1) as f64).sqrt()
+    }
+
+    /// 速度誤差のRMS（m/s）
+    pub fn rms_velocity_error(&self) -> f64 {
+        (self.sum_squared_velocity_error / self.samples.max(1) as f64).sqrt()
+    }
+
+    /// 位置推定の標準偏差の平均（メートル）
+    pub fn mean_position_sigma(&self) -> f64 {
+        self.sum_position_sigma / self.samples.max(1) as f64
+    }
+}
+
+/// 標準正規分布の乱数（Box-Muller法）
+fn standard_normal<R: Rng + ?Sized>(rng: &mut R) -> f64 {
+    let u1: f64 = rng.gen_range(f64::EPSILON..1.0);
+    let u2: f64 = rng.gen_range(0.0..1.0);
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
+
+fn add(a: &Matrix3, b: &Matrix3) -> Matrix3 {
+    std::array::from_fn(|i| std::array::from_fn(|j| a[i][j] + b[i][j]))
+}
+
+fn sub(a: &Matrix3, b: &Matrix3) -> Matrix3 {
+    std::array::from_fn(|i| std::array::from_fn(|j| a[i][j] - b[i][j]))
+}
+
+fn scale(a: &Matrix3, factor: f64) -> Matrix3 {
+    std::array::from_fn(|i| std::array::from_fn(|j| a[i][j] * factor))
+}
+
+fn transpose(a: &Matrix3) -> Matrix3 {
+    std::array::from_fn(|i| std::array::from_fn(|j| a[j][i]))
+}
+
+fn symmetrize(a: &Matrix3) -> Matrix3 {
+    scale(&add(a, &transpose(a)), 0.5)
+}
+
+fn multiply(a: &Matrix3, b: &Matrix3) -> Matrix3 {
+    std::array::from_fn(|i| std::array::from_fn(|j| (0..3).map(|k| a[i][k] * b[k][j]).sum()))
+}
+
+/// 3×3行列の逆行列（余因子展開、特異な場合はNone）
+fn invert(a: &Matrix3) -> Option<Matrix3> {
+    let cofactor = |i: usize, j: usize| {
+        let (r0, r1) = ((i + 1) % 3, (i + 2) % 3);
+        let (c0, c1) = ((j + 1) % 3, (j + 2) % 3);
+        a[r0][c0] * a[r1][c1] - a[r0][c1] * a[r1][c0]
+    };
+    let determinant: f64 = (0..3).map(|j| a[0][j] * cofactor(0, j)).sum();
+    if determinant.abs() < f64::MIN_POSITIVE {
+        return None;
+    }
+    Some(std::array::from_fn(|i| std::array::from_fn(|j| cofactor(j, i) / determinant)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::target::Target;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_track_converges_on_constant_velocity_target() {
+        let noise = MeasurementNoise { range_sigma: 50.0, azimuth_sigma_deg: 0.2, elevation_sigma_deg: 0.2 };
+        let sensor = Position3D::new(0.0, 0.0, 0.0);
+        let true_velocity = Velocity3D::new(-200.0, 50.0, 0.0);
+        let true_position_at = |time: f64| Position3D::new(60_000.0 - 200.0 * time, 10_000.0 + 50.0 * time, 1_000.0);
+        let mut rng = ChaCha8Rng::seed_from_u64(11);
+
+        let mut track = Track::new(&noise.measure(&mut rng, &sensor, &true_position_at(0.0), 0.0));
+        let mut stats = TrackErrorStats::default();
+        for step in 1..=120 {
+            let time = step as f64;
+            track.update(&noise.measure(&mut rng, &sensor, &true_position_at(time), time), 1.0);
+            if step > 60 {
+                stats.record(&track, &true_position_at(time), &true_velocity);
+            }
+        }
+
+        // 観測1回の位置誤差（約300m）より十分小さく収束し、速度も推定できる
+        assert_eq!(track.updates, 121);
+        assert!(stats.rms_position_error() < 150.0, "{}", stats.rms_position_error());
+        assert!(stats.rms_velocity_error() < 15.0, "{}", stats.rms_velocity_error());
+        let destination = Position3D::new(0.0, 40_000.0, 0.0);
+        let mut target = Target::new("T001".to_string(), true_position_at(120.0), destination, "G001".to_string());
+        target.set_parameters(0.0, 1, 0.0, true_velocity.magnitude_xy());
+        target.check_spawn(0.0);
+        let track_tgo = target.time_to_go_from(&track.estimated_position(), Some(&track.estimated_velocity()));
+        assert!((track_tgo / target.calculate_time_to_go() - 1.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_track_stays_stable_with_long_update_interval() {
+        // センサーの近くを通過するターゲットを2秒間隔で観測（観測誤差共分散の向きと大きさが急変する）
+        let noise = MeasurementNoise { range_sigma: 100.0, azimuth_sigma_deg: 0.3, elevation_sigma_deg: 0.3 };
+        let sensor = Position3D::new(0.0, 0.0, 50.0);
+        let true_position_at = |time: f64| Position3D::new(-100_000.0 + 150.0 * time, 2_000.0, 3_000.0);
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+
+        let mut track = Track::new(&noise.measure(&mut rng, &sensor, &true_position_at(0.0), 0.0));
+        let mut max_position_error: f64 = 0.0;
+        for step in 1..=700 {
+            let time = step as f64 * 2.0;
+            track.update(&noise.measure(&mut rng, &sensor, &true_position_at(time), time), DEFAULT_PROCESS_NOISE_MPS2);
+            let position_error = track.estimated_position().distance_3d(&true_position_at(time));
+            max_position_error = max_position_error.max(if position_error.is_nan() { f64::INFINITY } else { position_error });
+        }
+
+        // 共分散の対称性が崩れると発散する
+        assert!(max_position_error < 2_000.0, "{}", max_position_error);
+    }
+}
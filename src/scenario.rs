@@ -17,12 +17,28 @@
 //! 
 //! - **メタデータ**: シナリオ名、説明、バージョン情報
 //! - **シミュレーション設定**: 時間刻み（Δt）、最大時間、乱数シード
-//! - **世界設定**: シミュレーション領域、高度制限、距離計算方式
+//! - **世界設定**: シミュレーション領域、高度制限、距離計算方式、測地基準点
 //! - **指揮所設定**: 防衛対象の位置と到達判定範囲
+//! - **防護対象**: 航空基地・港湾など、敵グループが個別に狙う防護対象の位置・到達判定範囲・価値
 //! - **戦術ポリシー**: 優先度計算、ランチャー選択、ミサイル誘導設定
 //! - **友軍戦力**: センサーとランチャーの配置・性能
 //! - **敵軍戦力**: 敵グループの出現パターンと行動パラメータ
 //! 
+//! ## 測地座標による位置指定
+//! 
+//! `world.geodetic_reference` に基準点（緯度・経度・高度）を指定すると、
+//! 指揮所・センサー・ランチャー・敵グループ中心などの位置を `x_m` / `y_m` の代わりに
+//! `lat_deg` / `lon_deg`（3次元位置は `alt_m` または `z_m`）で記述できます。
+//! 測地座標は読み込み時に基準点を原点とするローカルENU座標（X=東, Y=北）へ変換され、
+//! 高度は基準点高度からの差としてZ座標に格納されます。
+//! 
+//! ```yaml
+//! world:
+//!   geodetic_reference: { lat_deg: 35.0, lon_deg: 135.0, alt_m: 0.0 }
+//! command_post:
+//!   position: { lat_deg: 34.95, lon_deg: 135.12 }
+//! ```
+//! 
 //! ## 使用例
 //! 
 //! ```rust
@@ -40,6 +56,8 @@ use std::path::Path;
 use std::fs;
 use tracing::{info, warn, error, debug};
 
+use crate::models::{GeodeticPosition, GeodeticReference};
+
 /// シナリオメタデータ
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ScenarioMeta {
@@ -62,6 +80,28 @@ pub struct WorldConfig {
     pub region_rect: RegionRect,
     pub z_limits_m: [f64; 2],
     pub distance_conventions: DistanceConventions,
+    /// ローカル座標原点の測地座標（省略時は測地座標による位置指定不可）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub geodetic_reference: Option<GeodeticReferenceConfig>,
+    /// 4/3地球半径モデルのレーダー水平線による見通し判定を行うか（省略時は行わない）
+    #[serde(default)]
+    pub radar_horizon: bool,
+}
+
+/// 測地基準点設定
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub struct GeodeticReferenceConfig {
+    pub lat_deg: f64,
+    pub lon_deg: f64,
+    #[serde(default)]
+    pub alt_m: f64,
+}
+
+impl GeodeticReferenceConfig {
+    /// 測地座標に変換
+    pub fn to_geodetic(self) -> GeodeticPosition {
+        GeodeticPosition::new(self.lat_deg, self.lon_deg, self.alt_m)
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -72,6 +112,13 @@ pub struct RegionRect {
     pub ymax_m: f64,
 }
 
+impl RegionRect {
+    /// XY位置が矩形内（境界を含む）にあるか
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        (self.xmin_m..=self.xmax_m).contains(&x) && (self.ymin_m..=self.ymax_m).contains(&y)
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct DistanceConventions {
     pub breakthrough: String,
@@ -85,6 +132,47 @@ pub struct DistanceConventions {
 pub struct CommandPostConfig {
     pub position: Position2D,
     pub arrival_radius_m: f64,
+    /// 防護対象としての価値（目標優先度の重み）
+    #[serde(default = "default_asset_value")]
+    pub value: f64,
+    /// デコイ識別モデル（省略時はデコイを識別しない）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decoy_discrimination: Option<DecoyDiscriminationConfig>,
+    /// 航跡フィルタのプロセス雑音（加速度の標準偏差、m/s²）
+    #[serde(default = "default_track_process_noise_mps2")]
+    pub track_process_noise_mps2: f64,
+}
+
+fn default_track_process_noise_mps2() -> f64 {
+    10.0
+}
+
+/// デコイ識別モデル設定
+/// 
+/// 初回検知から `tracking_time_s` 追跡した時点で、デコイを確率 `probability` で識別します。
+/// 識別されたデコイは目標優先度リストから除外されます。
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DecoyDiscriminationConfig {
+    pub tracking_time_s: f64,
+    pub probability: f64,
+}
+
+/// 防護対象設定
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DefendedAssetConfig {
+    pub id: String,
+    /// 種別（airbase, port など）
+    #[serde(default)]
+    pub r#type: String,
+    pub position: Position2D,
+    pub arrival_radius_m: f64,
+    /// 防護対象の価値（目標優先度の重み）
+    #[serde(default = "default_asset_value")]
+    pub value: f64,
+}
+
+fn default_asset_value() -> f64 {
+    1.0
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -134,6 +222,13 @@ pub struct MissileKinematics {
     pub max_accel_mps2: f64,
     pub max_turn_rate_deg_s: f64,
     pub intercept_radius_m: f64,
+    /// 迎撃ミサイルの上昇限度（メートル、省略時 5000）。弾道ミサイル型を高高度で迎撃する場合に引き上げる
+    #[serde(default = "default_missile_max_altitude_m")]
+    pub max_altitude_m: f64,
+}
+
+fn default_missile_max_altitude_m() -> f64 {
+    5000.0
 }
 
 /// 友軍設定
@@ -142,13 +237,159 @@ pub struct FriendlyForcesConfig {
     pub deploy_rect_xy: Option<RegionRect>,
     pub sensors: Vec<SensorConfig>,
     pub launchers: Vec<LauncherConfig>,
+    /// 複数センサーの観測のシステム航跡への融合（省略時はセンサーごとの検知を指揮所で統合）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sensor_fusion: Option<SensorFusionConfig>,
+}
+
+/// センサー航跡融合設定
+/// 
+/// 各センサーの雑音付き観測をゲーティングと最近傍法でシステム航跡に相関・融合します。
+/// 全センサーに `measurement_noise` の指定が必要です。
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SensorFusionConfig {
+    /// 相関のゲート（マハラノビス距離の2乗、省略時は自由度3のカイ二乗分布の99.9%点 16.27）
+    #[serde(default = "default_fusion_gate")]
+    pub gate: f64,
+    /// 観測のないシステム航跡を消去するまでの時間（秒、省略時 10.0）
+    #[serde(default = "default_fusion_coast_time_s")]
+    pub coast_time_s: f64,
+}
+
+fn default_fusion_gate() -> f64 {
+    16.27
+}
+
+fn default_fusion_coast_time_s() -> f64 {
+    10.0
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct SensorConfig {
     pub id: String,
     pub pos: Position3D,
+    /// 基準RCSのターゲットに対する探知範囲
     pub range_m: f64,
+    /// `range_m` の基準となるレーダー反射断面積（平方メートル、省略時 1.0）
+    #[serde(default = "default_reference_rcs_m2")]
+    pub reference_rcs_m2: f64,
+    /// 方位・仰角の視野制限（省略時は全方位）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field_of_view: Option<FieldOfViewConfig>,
+    /// 回転走査（省略時は常時全視野を監視）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scan: Option<ScanConfig>,
+    /// 観測雑音（省略時は真値を通知）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub measurement_noise: Option<MeasurementNoiseConfig>,
+    /// 探知確率モデル（省略時は探知範囲内で常に探知）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detection_probability: Option<DetectionProbabilityConfig>,
+    /// 探知の更新周期（秒、省略時は毎ステップ更新）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update_period_s: Option<f64>,
+    /// 探知結果が指揮所に届くまでの報告遅延（秒、省略時は0）
+    #[serde(default)]
+    pub report_latency_s: f64,
+    /// 同時に維持できる航跡数（省略時は無制限）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_tracks: Option<usize>,
+    /// 航跡容量を超えた場合に維持する航跡の優先順位（省略時は距離順）
+    #[serde(default)]
+    pub track_priority: TrackPriorityKind,
+}
+
+/// センサーの航跡容量を超えた場合の優先順位
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrackPriorityKind {
+    /// センサーからの距離が近い順
+    #[default]
+    Range,
+    /// 指揮所の目標優先度順
+    CommandPost,
+}
+
+/// センサー探知確率モデル設定
+/// 
+/// 基準RCSのターゲットに対する `range_m` でのSNRを `reference_snr_db` とし、
+/// 誤警報確率 `false_alarm_probability` から定めたしきい値（`threshold` 指定時はその値）で観測ごとに探知を判定します。
+/// 直近 `confirm_n` 回の観測のうち `confirm_m` 回以上探知した時点で航跡を確立します。
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DetectionProbabilityConfig {
+    #[serde(default = "default_reference_snr_db")]
+    pub reference_snr_db: f64,
+    #[serde(default = "default_false_alarm_probability")]
+    pub false_alarm_probability: f64,
+    /// 探知しきい値（雑音電力で正規化、省略時は誤警報確率から決定）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threshold: Option<f64>,
+    #[serde(default = "default_confirm_m")]
+    pub confirm_m: u32,
+    #[serde(default = "default_confirm_n")]
+    pub confirm_n: u32,
+    /// 乱数シード（省略時はシミュレーションのシード値から決定）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+}
+
+fn default_reference_snr_db() -> f64 {
+    13.0
+}
+
+fn default_false_alarm_probability() -> f64 {
+    1e-6
+}
+
+fn default_confirm_m() -> u32 {
+    2
+}
+
+fn default_confirm_n() -> u32 {
+    3
+}
+
+/// センサー観測雑音設定（各観測値の標準偏差）
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MeasurementNoiseConfig {
+    pub range_sigma_m: f64,
+    pub azimuth_sigma_deg: f64,
+    pub elevation_sigma_deg: f64,
+}
+
+fn default_reference_rcs_m2() -> f64 {
+    1.0
+}
+
+/// センサー視野設定
+/// 
+/// 方位角は `angle_reference` の規約に従い、`azimuth_width_deg` はボアサイトを中心とした全幅です。
+#[derive(Debug, Deserialize, Serialize)]
+pub struct FieldOfViewConfig {
+    pub boresight_az_deg: f64,
+    pub azimuth_width_deg: f64,
+    #[serde(default = "default_min_elevation_deg")]
+    pub min_elevation_deg: f64,
+    #[serde(default = "default_max_elevation_deg")]
+    pub max_elevation_deg: f64,
+}
+
+fn default_min_elevation_deg() -> f64 {
+    -90.0
+}
+
+fn default_max_elevation_deg() -> f64 {
+    90.0
+}
+
+/// センサー回転走査設定
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ScanConfig {
+    pub rpm: f64,
+    pub beam_width_deg: f64,
+    /// 時刻0のビーム方位角（度）
+    #[serde(default)]
+    pub start_az_deg: f64,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -157,6 +398,9 @@ pub struct LauncherConfig {
     pub pos: Position3D,
     pub missiles_loaded: u32,
     pub cooldown_s: f64,
+    /// 搭載するミサイル種別のID（`missile_defaults.types` を参照、省略時はデフォルトの迎撃効果）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub missile_type: Option<String>,
 }
 
 /// 敵軍設定
@@ -178,6 +422,205 @@ pub struct EnemyGroupConfig {
     pub ring_half_offset: bool,
     pub endurance_pt: u32,
     pub speed_mps: f64,
+    /// グループ内の各機の出現間隔（秒、i番目の機は `spawn_time_s + i × spawn_interval_s` に出現）
+    #[serde(default)]
+    pub spawn_interval_s: f64,
+    /// 出現時刻・速度・出現位置のランダムなばらつき（省略時はばらつきなし）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spawn_jitter: Option<SpawnJitterConfig>,
+    /// 攻撃目標の防護対象ID、または制圧攻撃の対象とする友軍センサー・ランチャーのID（省略時は指揮所）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_asset: Option<String>,
+    /// 到達判定範囲（省略時は攻撃目標の到達判定範囲、センサー・ランチャーは 1000m）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub arrival_radius_m: Option<f64>,
+    /// 編隊形状（省略時は同心円リング配置）
+    #[serde(default)]
+    pub formation: FormationConfig,
+    /// 指揮所への最終進入前に経由する経路点（通過順）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub waypoints: Vec<WaypointConfig>,
+    /// ミサイル終盤での回避機動（省略時は回避しない）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maneuver: Option<ManeuverConfig>,
+    /// 弾道ミサイル型の設定（指定時は `center_xy` / `z_m` を発射点とする重力弾道で飛翔）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ballistic: Option<BallisticConfig>,
+    /// 巡航ミサイル型の設定（指定時は対地高度を保つ低高度飛行、`z_m` は使用しない）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cruise: Option<CruiseConfig>,
+    /// 最終進入時の高度プロファイル（省略時は `z_m` を保つ）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub altitude_profile: Option<AltitudeProfileConfig>,
+    /// グループ内のデコイの割合（0.0〜1.0、省略時 0.0）
+    #[serde(default)]
+    pub decoy_ratio: f64,
+    /// レーダー反射断面積（平方メートル、省略時は巡航ミサイル型なら `cruise.rcs_m2`、それ以外は 1.0）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rcs_m2: Option<f64>,
+}
+
+impl EnemyGroupConfig {
+    /// グループ内ターゲットのレーダー反射断面積（平方メートル）
+    pub fn signature_rcs_m2(&self) -> f64 {
+        self.rcs_m2
+            .or_else(|| self.cruise.as_ref().map(|cruise| cruise.rcs_m2))
+            .unwrap_or(1.0)
+    }
+}
+
+/// 弾道ミサイル型設定
+/// 
+/// `apogee_m`（最高到達高度）と `flight_time_s`（飛翔時間）のどちらか一方を指定します。
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BallisticConfig {
+    /// 着弾点（省略時は攻撃目標の防護対象の位置）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub impact_xy: Option<Position2D>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub apogee_m: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flight_time_s: Option<f64>,
+}
+
+/// 巡航ミサイルの終末機動
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TerminalKind {
+    #[default]
+    None,
+    PopUp,
+    Dive,
+}
+
+/// 巡航ミサイル型設定
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CruiseConfig {
+    /// 巡航時の対地高度（メートル）
+    pub altitude_agl_m: f64,
+    /// 終末機動（省略時は巡航高度のまま進入）
+    #[serde(default)]
+    pub terminal: TerminalKind,
+    /// 終末機動を開始する目的地までの水平距離（メートル）
+    #[serde(default)]
+    pub terminal_range_m: f64,
+    /// ポップアップ時の最高高度（メートル）
+    #[serde(default)]
+    pub pop_up_altitude_m: f64,
+    /// レーダー反射断面積（平方メートル、センサーの実効探知範囲を縮小）
+    #[serde(default = "default_cruise_rcs_m2")]
+    pub rcs_m2: f64,
+}
+
+fn default_cruise_rcs_m2() -> f64 {
+    0.1
+}
+
+/// 回避機動の種類
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ManeuverKind {
+    Jink,
+    BarrelRoll,
+    BreakTurn,
+}
+
+/// 回避機動設定
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ManeuverConfig {
+    pub r#type: ManeuverKind,
+    pub max_g: f64,
+    pub duration_s: f64,
+    /// ジンクの方向変更間隔 / バレルロールの1回転周期（秒）
+    #[serde(default = "default_maneuver_period_s")]
+    pub period_s: f64,
+    /// 交戦中のミサイルがこの距離以内に入ったら開始（省略時は終盤フェーズ移行時のみ）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trigger_range_m: Option<f64>,
+}
+
+/// 高度プロファイル設定
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AltitudeProfileConfig {
+    /// 巡航高度（省略時は `z_m`）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cruise_altitude_m: Option<f64>,
+    /// 降下を開始する目的地までの水平距離
+    pub descent_start_m: f64,
+    /// 終末ダイブ角（水平からの角度）
+    pub dive_angle_deg: f64,
+}
+
+/// 出現時のばらつき設定
+/// 
+/// 各値は一様分布の幅で、出現時刻・速度は ±値、出現位置は半径内の水平方向に加えます。
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SpawnJitterConfig {
+    #[serde(default)]
+    pub time_s: f64,
+    #[serde(default)]
+    pub speed_mps: f64,
+    #[serde(default)]
+    pub position_m: f64,
+    /// 乱数シード（省略時はシミュレーションのシード値から決定）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+}
+
+fn default_maneuver_period_s() -> f64 {
+    1.0
+}
+
+/// 編隊形状設定
+/// 
+/// `heading_deg` は `angle_reference` の規約（+X軸基準・反時計回り）に従い、
+/// 省略時は指揮所方向を進行方向とします。
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FormationConfig {
+    /// 中心1機＋同心円リング（`ring_spacing_m` / `start_angle_deg` / `ring_half_offset` を使用）
+    #[default]
+    Rings,
+    LineAbreast {
+        spacing_m: f64,
+        #[serde(default)]
+        heading_deg: Option<f64>,
+    },
+    Column {
+        spacing_m: f64,
+        #[serde(default)]
+        heading_deg: Option<f64>,
+    },
+    Wedge {
+        spacing_m: f64,
+        #[serde(default)]
+        heading_deg: Option<f64>,
+    },
+    Box {
+        spacing_m: f64,
+        #[serde(default)]
+        heading_deg: Option<f64>,
+        /// 列数（省略時は機数の平方根を切り上げ）
+        #[serde(default)]
+        columns: Option<u32>,
+    },
+    Scatter {
+        radius_m: f64,
+        /// 乱数シード（省略時はシミュレーションのシード値から決定）
+        #[serde(default)]
+        seed: Option<u64>,
+    },
+}
+
+/// 経路点設定
+#[derive(Debug, Deserialize, Serialize)]
+pub struct WaypointConfig {
+    pub x_m: f64,
+    pub y_m: f64,
+    pub z_m: f64,
+    /// この経路点までの区間速度（省略時はグループの速度）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speed_mps: Option<f64>,
 }
 
 /// 完全なシナリオ設定
@@ -191,12 +634,64 @@ pub struct ScenarioConfig {
     pub friendly_forces: FriendlyForcesConfig,
     pub enemy_forces: EnemyForcesConfig,
     pub missile_defaults: MissileDefaults,
+    /// 指揮所以外の防護対象
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub defended_assets: Vec<DefendedAssetConfig>,
 }
 
 /// ミサイルデフォルト設定
 #[derive(Debug, Deserialize, Serialize)]
 pub struct MissileDefaults {
     pub kinematics: MissileKinematics,
+    /// 種別を指定しないミサイルの迎撃効果（省略時は命中ごとに耐久値を1減少）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lethality: Option<LethalityConfig>,
+    /// ミサイル種別ごとの迎撃効果
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub types: Vec<MissileTypeConfig>,
+}
+
+/// ミサイル種別設定
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MissileTypeConfig {
+    pub id: String,
+    pub lethality: LethalityConfig,
+}
+
+/// 迎撃効果設定
+/// 
+/// 命中ごとに撃墜確率 `pk` で撃墜、撃墜に至らなかった場合は確率 `damage_probability` で
+/// 損傷（耐久値1減少）、それ以外は効果なしとします。
+#[derive(Debug, Deserialize, Serialize)]
+pub struct LethalityConfig {
+    pub pk: f64,
+    /// 迎撃判定距離ちょうどでのmiss distanceにおける撃墜確率（省略時はmiss distanceによらない）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pk_at_radius: Option<f64>,
+    /// これを超える接近速度で撃墜確率を低下させる（m/s、省略時は接近速度によらない）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_closing_speed_mps: Option<f64>,
+    #[serde(default)]
+    pub damage_probability: f64,
+}
+
+impl LethalityConfig {
+    fn is_valid(&self) -> bool {
+        (0.0..=1.0).contains(&self.pk)
+            && self.pk_at_radius.is_none_or(|pk| (0.0..=1.0).contains(&pk))
+            && self.max_closing_speed_mps.is_none_or(|speed| speed > 0.0)
+            && (0.0..=1.0).contains(&self.damage_probability)
+    }
+}
+
+impl MissileDefaults {
+    /// ランチャーの搭載ミサイル種別に対応する迎撃効果
+    pub fn lethality_for(&self, missile_type: Option<&str>) -> Option<&LethalityConfig> {
+        match missile_type {
+            Some(type_id) => self.types.iter().find(|t| t.id == type_id).map(|t| &t.lethality),
+            None => self.lethality.as_ref(),
+        }
+    }
 }
 
 impl ScenarioConfig {
@@ -213,8 +708,11 @@ impl ScenarioConfig {
         let contents = fs::read_to_string(path)
             .map_err(|e| ScenarioError::IoError(path.to_path_buf(), e))?;
         
-        // YAML解析
-        let config: ScenarioConfig = serde_yaml::from_str(&contents)
+        // YAML解析（測地座標による位置指定はローカル座標に変換してから読み込む）
+        let mut document: serde_yaml::Value = serde_yaml::from_str(&contents)
+            .map_err(|e| ScenarioError::ParseError(path.to_path_buf(), e))?;
+        resolve_geodetic_positions(&mut document)?;
+        let config: ScenarioConfig = serde_yaml::from_value(document)
             .map_err(|e| ScenarioError::ParseError(path.to_path_buf(), e))?;
         
         // 基本的な検証
@@ -251,19 +749,284 @@ impl ScenarioConfig {
             return Err(ScenarioError::ValidationError("Command post outside region bounds".to_string()));
         }
         
+        if self.command_post.value <= 0.0 {
+            return Err(ScenarioError::ValidationError("Command post value must be positive".to_string()));
+        }
+        
+        if self.command_post.track_process_noise_mps2 <= 0.0 {
+            return Err(ScenarioError::ValidationError("track_process_noise_mps2 must be positive".to_string()));
+        }
+        
+        if let Some(discrimination) = &self.command_post.decoy_discrimination
+            && (discrimination.tracking_time_s < 0.0 || !(0.0..=1.0).contains(&discrimination.probability))
+        {
+            return Err(ScenarioError::ValidationError(
+                "decoy_discrimination needs a non-negative tracking_time_s and a probability within [0, 1]".to_string()
+            ));
+        }
+        
+        for sensor in &self.friendly_forces.sensors {
+            if sensor.reference_rcs_m2 <= 0.0 {
+                return Err(ScenarioError::ValidationError(
+                    format!("Sensor {} reference_rcs_m2 must be positive", sensor.id)
+                ));
+            }
+            if let Some(fov) = &sensor.field_of_view
+                && (fov.azimuth_width_deg <= 0.0
+                    || fov.azimuth_width_deg > 360.0
+                    || fov.min_elevation_deg < -90.0
+                    || fov.max_elevation_deg > 90.0
+                    || fov.min_elevation_deg >= fov.max_elevation_deg)
+            {
+                return Err(ScenarioError::ValidationError(format!(
+                    "Sensor {} field_of_view needs azimuth_width_deg within (0, 360] and min_elevation_deg < max_elevation_deg within [-90, 90]",
+                    sensor.id
+                )));
+            }
+            if let Some(noise) = &sensor.measurement_noise
+                && (noise.range_sigma_m <= 0.0 || noise.azimuth_sigma_deg <= 0.0 || noise.elevation_sigma_deg <= 0.0)
+            {
+                return Err(ScenarioError::ValidationError(
+                    format!("Sensor {} measurement_noise sigmas must be positive", sensor.id)
+                ));
+            }
+            if let Some(pd) = &sensor.detection_probability
+                && (pd.false_alarm_probability <= 0.0
+                    || pd.false_alarm_probability >= 1.0
+                    || pd.threshold.is_some_and(|threshold| threshold <= 0.0)
+                    || pd.confirm_m == 0
+                    || pd.confirm_m > pd.confirm_n
+                    || pd.confirm_n > 32)
+            {
+                return Err(ScenarioError::ValidationError(format!(
+                    "Sensor {} detection_probability needs false_alarm_probability within (0, 1), a positive threshold and 1 <= confirm_m <= confirm_n <= 32",
+                    sensor.id
+                )));
+            }
+            if sensor.max_tracks == Some(0) {
+                return Err(ScenarioError::ValidationError(
+                    format!("Sensor {} max_tracks must be positive", sensor.id)
+                ));
+            }
+            if sensor.update_period_s.is_some_and(|period| period <= 0.0) || sensor.report_latency_s < 0.0 {
+                return Err(ScenarioError::ValidationError(format!(
+                    "Sensor {} needs a positive update_period_s and a non-negative report_latency_s",
+                    sensor.id
+                )));
+            }
+            if let Some(scan) = &sensor.scan
+                && (scan.rpm <= 0.0 || scan.beam_width_deg <= 0.0 || scan.beam_width_deg > 360.0)
+            {
+                return Err(ScenarioError::ValidationError(
+                    format!("Sensor {} scan needs a positive rpm and beam_width_deg within (0, 360]", sensor.id)
+                ));
+            }
+        }
+        
+        if let Some(fusion) = &self.friendly_forces.sensor_fusion {
+            if fusion.gate <= 0.0 || fusion.coast_time_s <= 0.0 {
+                return Err(ScenarioError::ValidationError(
+                    "sensor_fusion needs a positive gate and coast_time_s".to_string()
+                ));
+            }
+            if let Some(sensor) = self.friendly_forces.sensors.iter().find(|s| s.measurement_noise.is_none()) {
+                return Err(ScenarioError::ValidationError(
+                    format!("Sensor {} needs measurement_noise when sensor_fusion is enabled", sensor.id)
+                ));
+            }
+        }
+        
+        // ミサイル種別の検証
+        if self.missile_defaults.kinematics.max_altitude_m <= 0.0 {
+            return Err(ScenarioError::ValidationError(
+                "missile_defaults.kinematics.max_altitude_m must be positive".to_string()
+            ));
+        }
+        if self.missile_defaults.lethality.as_ref().is_some_and(|lethality| !lethality.is_valid()) {
+            return Err(ScenarioError::ValidationError(
+                "missile_defaults.lethality probabilities must be within [0, 1] and max_closing_speed_mps positive".to_string()
+            ));
+        }
+        for (index, missile_type) in self.missile_defaults.types.iter().enumerate() {
+            if self.missile_defaults.types[..index].iter().any(|other| other.id == missile_type.id) {
+                return Err(ScenarioError::ValidationError(format!("Duplicate missile type id {}", missile_type.id)));
+            }
+            if !missile_type.lethality.is_valid() {
+                return Err(ScenarioError::ValidationError(format!(
+                    "Missile type {} lethality probabilities must be within [0, 1] and max_closing_speed_mps positive",
+                    missile_type.id
+                )));
+            }
+        }
+        for launcher in &self.friendly_forces.launchers {
+            if let Some(type_id) = &launcher.missile_type
+                && !self.missile_defaults.types.iter().any(|t| &t.id == type_id)
+            {
+                return Err(ScenarioError::ValidationError(
+                    format!("Launcher {} uses unknown missile type {}", launcher.id, type_id)
+                ));
+            }
+        }
+        
+        // 防護対象の検証
+        for (index, asset) in self.defended_assets.iter().enumerate() {
+            if self.defended_assets[..index].iter().any(|other| other.id == asset.id) {
+                return Err(ScenarioError::ValidationError(format!("Duplicate defended asset id {}", asset.id)));
+            }
+            if !self.is_position_in_bounds(asset.position.x_m, asset.position.y_m) {
+                return Err(ScenarioError::ValidationError(format!("Defended asset {} outside region bounds", asset.id)));
+            }
+            if asset.arrival_radius_m <= 0.0 || asset.value <= 0.0 {
+                return Err(ScenarioError::ValidationError(
+                    format!("Defended asset {} arrival_radius_m and value must be positive", asset.id)
+                ));
+            }
+        }
+        
         // 敵グループのスポーン時刻検証
         for group in &self.enemy_forces.groups {
+            if let Some(asset_id) = &group.target_asset
+                && !self.defended_assets.iter().any(|asset| &asset.id == asset_id)
+                && !self.friendly_forces.sensors.iter().any(|sensor| &sensor.id == asset_id)
+                && !self.friendly_forces.launchers.iter().any(|launcher| &launcher.id == asset_id)
+            {
+                return Err(ScenarioError::ValidationError(
+                    format!("Group {} targets unknown defended asset, sensor or launcher {}", group.id, asset_id)
+                ));
+            }
             if group.spawn_time_s >= self.sim.t_max_s {
                 return Err(ScenarioError::ValidationError(
                     format!("Group {} spawn time {} >= simulation time {}", 
                             group.id, group.spawn_time_s, self.sim.t_max_s)
                 ));
             }
+            if !self.enemy_forces.spawn_rect_xy.contains(group.center_xy.x_m, group.center_xy.y_m) {
+                return Err(ScenarioError::ValidationError(
+                    format!("Group {} center is outside enemy_forces.spawn_rect_xy", group.id)
+                ));
+            }
+            if group.spawn_interval_s < 0.0 {
+                return Err(ScenarioError::ValidationError(
+                    format!("Group {} spawn_interval_s must not be negative", group.id)
+                ));
+            }
+            if let Some(jitter) = &group.spawn_jitter
+                && (jitter.time_s < 0.0 || jitter.speed_mps < 0.0 || jitter.position_m < 0.0
+                    || jitter.speed_mps >= group.speed_mps)
+            {
+                return Err(ScenarioError::ValidationError(
+                    format!("Group {} spawn_jitter values must be non-negative and speed_mps below the group speed", group.id)
+                ));
+            }
+            if let Some(profile) = &group.altitude_profile {
+                if group.ballistic.is_some() || group.cruise.is_some() {
+                    return Err(ScenarioError::ValidationError(
+                        format!("Group {} altitude_profile cannot be combined with ballistic or cruise", group.id)
+                    ));
+                }
+                if profile.descent_start_m < 0.0
+                    || profile.dive_angle_deg <= 0.0
+                    || profile.dive_angle_deg >= 90.0
+                    || profile.cruise_altitude_m.is_some_and(|altitude| !(0.0..=5000.0).contains(&altitude))
+                {
+                    return Err(ScenarioError::ValidationError(format!(
+                        "Group {} altitude_profile needs descent_start_m >= 0, dive_angle_deg within (0, 90) and cruise_altitude_m within [0, 5000]",
+                        group.id
+                    )));
+                }
+            }
+            if group.arrival_radius_m.is_some_and(|radius| radius <= 0.0) {
+                return Err(ScenarioError::ValidationError(format!("Group {} arrival_radius_m must be positive", group.id)));
+            }
+            if !(0.0..=1.0).contains(&group.decoy_ratio) {
+                return Err(ScenarioError::ValidationError(format!("Group {} decoy_ratio must be within [0, 1]", group.id)));
+            }
+            if group.rcs_m2.is_some_and(|rcs| rcs <= 0.0) {
+                return Err(ScenarioError::ValidationError(format!("Group {} rcs_m2 must be positive", group.id)));
+            }
+            // リング配置は従来どおり ring_spacing_m を検証しない
+            let formation_size = match &group.formation {
+                FormationConfig::Rings => None,
+                FormationConfig::LineAbreast { spacing_m, .. }
+                | FormationConfig::Column { spacing_m, .. }
+                | FormationConfig::Wedge { spacing_m, .. }
+                | FormationConfig::Box { spacing_m, .. } => Some(*spacing_m),
+                FormationConfig::Scatter { radius_m, .. } => Some(*radius_m),
+            };
+            if formation_size.is_some_and(|size| size <= 0.0) {
+                return Err(ScenarioError::ValidationError(
+                    format!("Group {} formation spacing must be positive", group.id)
+                ));
+            }
+            if let Some(ballistic) = &group.ballistic {
+                match (ballistic.apogee_m, ballistic.flight_time_s) {
+                    (Some(apogee), None) if apogee > group.z_m => {}
+                    (None, Some(flight_time)) if flight_time > 0.0 => {}
+                    _ => {
+                        return Err(ScenarioError::ValidationError(format!(
+                            "Group {} ballistic needs either apogee_m above z_m or a positive flight_time_s", group.id
+                        )));
+                    }
+                }
+                if !group.waypoints.is_empty() || group.maneuver.is_some() {
+                    return Err(ScenarioError::ValidationError(
+                        format!("Group {} ballistic cannot be combined with waypoints or maneuver", group.id)
+                    ));
+                }
+            }
+            if let Some(cruise) = &group.cruise {
+                if group.ballistic.is_some() {
+                    return Err(ScenarioError::ValidationError(
+                        format!("Group {} cannot be both ballistic and cruise", group.id)
+                    ));
+                }
+                if cruise.altitude_agl_m <= 0.0 || cruise.altitude_agl_m > 5000.0 || cruise.rcs_m2 <= 0.0 {
+                    return Err(ScenarioError::ValidationError(
+                        format!("Group {} cruise needs altitude_agl_m within (0, 5000] and a positive rcs_m2", group.id)
+                    ));
+                }
+                if cruise.terminal != TerminalKind::None && cruise.terminal_range_m <= 0.0 {
+                    return Err(ScenarioError::ValidationError(
+                        format!("Group {} cruise terminal maneuver needs a positive terminal_range_m", group.id)
+                    ));
+                }
+                if cruise.terminal == TerminalKind::PopUp
+                    && (cruise.pop_up_altitude_m <= cruise.altitude_agl_m || cruise.pop_up_altitude_m > 5000.0)
+                {
+                    return Err(ScenarioError::ValidationError(
+                        format!("Group {} cruise pop_up_altitude_m must be above altitude_agl_m and at most 5000", group.id)
+                    ));
+                }
+            }
+            if let Some(maneuver) = &group.maneuver
+                && (maneuver.max_g <= 0.0 || maneuver.duration_s <= 0.0 || maneuver.period_s <= 0.0)
+            {
+                return Err(ScenarioError::ValidationError(
+                    format!("Group {} maneuver max_g, duration_s and period_s must be positive", group.id)
+                ));
+            }
+            for (index, waypoint) in group.waypoints.iter().enumerate() {
+                if waypoint.speed_mps.is_some_and(|speed| speed <= 0.0) {
+                    return Err(ScenarioError::ValidationError(
+                        format!("Group {} waypoint {} speed must be positive", group.id, index)
+                    ));
+                }
+                if !self.is_position_in_bounds(waypoint.x_m, waypoint.y_m) {
+                    return Err(ScenarioError::ValidationError(
+                        format!("Group {} waypoint {} outside region bounds", group.id, index)
+                    ));
+                }
+            }
         }
         
         Ok(())
     }
     
+    /// ローカル座標原点の測地座標（シナリオで指定されている場合）
+    pub fn geodetic_origin(&self) -> Option<GeodeticPosition> {
+        self.world.geodetic_reference.map(GeodeticReferenceConfig::to_geodetic)
+    }
+    
     /// 位置が領域内かどうかをチェック
     fn is_position_in_bounds(&self, x: f64, y: f64) -> bool {
         let region = &self.world.region_rect;
@@ -282,6 +1045,10 @@ impl ScenarioConfig {
         info!("時間刻み: {:.3}秒", self.sim.dt_s);
         info!("最大時間: {:.1}秒 ({:.1}分)", self.sim.t_max_s, self.sim.t_max_s / 60.0);
         info!("シード値: {}", self.sim.seed);
+        if let Some(origin) = self.geodetic_origin() {
+            info!("測地基準点: 緯度 {:.6}°, 経度 {:.6}°, 高度 {:.1}m",
+                  origin.latitude_deg, origin.longitude_deg, origin.altitude_m);
+        }
         
         info!("=== 友軍戦力 ===");
         info!("センサー: {}基", self.friendly_forces.sensors.len());
@@ -289,15 +1056,99 @@ impl ScenarioConfig {
         let total_missiles: u32 = self.friendly_forces.launchers.iter().map(|l| l.missiles_loaded).sum();
         info!("総ミサイル数: {}発", total_missiles);
         
+        if !self.defended_assets.is_empty() {
+            info!("=== 防護対象 ===");
+            for asset in &self.defended_assets {
+                info!("  {} ({}): 到達判定範囲 {:.1}km, 価値 {:.1}",
+                      asset.id, asset.r#type, asset.arrival_radius_m / 1000.0, asset.value);
+            }
+        }
+        
         info!("=== 敵軍戦力 ===");
         info!("敵グループ数: {}", self.enemy_forces.groups.len());
         let total_enemies: u32 = self.enemy_forces.groups.iter().map(|g| g.count).sum();
         info!("総敵機数: {}機", total_enemies);
         
         for group in &self.enemy_forces.groups {
-            info!("  {}: {}機 (出現時刻: {:.1}秒)", group.id, group.count, group.spawn_time_s);
+            if group.waypoints.is_empty() {
+                info!("  {}: {}機 (出現時刻: {:.1}秒)", group.id, group.count, group.spawn_time_s);
+            } else {
+                info!("  {}: {}機 (出現時刻: {:.1}秒, 経路点: {}点)",
+                      group.id, group.count, group.spawn_time_s, group.waypoints.len());
+            }
+        }
+    }
+}
+
+/// 測地座標で指定された位置をローカル座標に置き換え
+///
+/// `world` 以外の全ての要素を走査し、`lat_deg` と `lon_deg` を持つマッピングを
+/// `x_m` / `y_m`（`alt_m` があれば `z_m` も）に変換します。
+fn resolve_geodetic_positions(document: &mut serde_yaml::Value) -> Result<(), ScenarioError> {
+    let reference = document
+        .get("world")
+        .and_then(|world| world.get("geodetic_reference"))
+        .cloned()
+        .map(serde_yaml::from_value::<GeodeticReferenceConfig>)
+        .transpose()
+        .map_err(|e| ScenarioError::ValidationError(format!("Invalid geodetic_reference: {}", e)))?
+        .map(|config| GeodeticReference::new(config.to_geodetic()));
+
+    let serde_yaml::Value::Mapping(root) = document else {
+        return Ok(());
+    };
+    for (key, value) in root.iter_mut() {
+        if key.as_str() != Some("world") {
+            resolve_geodetic_value(value, reference.as_ref(), &key_name(key))?;
+        }
+    }
+    Ok(())
+}
+
+fn key_name(key: &serde_yaml::Value) -> String {
+    key.as_str().map(str::to_string).unwrap_or_else(|| format!("{:?}", key))
+}
+
+fn resolve_geodetic_value(
+    value: &mut serde_yaml::Value,
+    reference: Option<&GeodeticReference>,
+    path: &str,
+) -> Result<(), ScenarioError> {
+    match value {
+        serde_yaml::Value::Mapping(mapping) => {
+            let lat = mapping.get("lat_deg").and_then(serde_yaml::Value::as_f64);
+            let lon = mapping.get("lon_deg").and_then(serde_yaml::Value::as_f64);
+            if let (Some(lat_deg), Some(lon_deg)) = (lat, lon) {
+                let reference = reference.ok_or_else(|| {
+                    ScenarioError::ValidationError(format!(
+                        "{} is given in lat/lon but world.geodetic_reference is not set", path
+                    ))
+                })?;
+                let altitude = mapping.get("alt_m").and_then(serde_yaml::Value::as_f64);
+                let local = reference.geodetic_to_local(&GeodeticPosition::new(lat_deg, lon_deg, altitude.unwrap_or(0.0)));
+
+                for key in ["lat_deg", "lon_deg", "alt_m"] {
+                    mapping.remove(key);
+                }
+                mapping.insert("x_m".into(), local.x.into());
+                mapping.insert("y_m".into(), local.y.into());
+                if altitude.is_some() {
+                    mapping.insert("z_m".into(), local.z.into());
+                }
+                return Ok(());
+            }
+            for (key, child) in mapping.iter_mut() {
+                resolve_geodetic_value(child, reference, &format!("{}.{}", path, key_name(key)))?;
+            }
+        }
+        serde_yaml::Value::Sequence(items) => {
+            for (index, child) in items.iter_mut().enumerate() {
+                resolve_geodetic_value(child, reference, &format!("{}[{}]", path, index))?;
+            }
         }
+        _ => {}
     }
+    Ok(())
 }
 
 /// シナリオ読み込みエラー
@@ -26,6 +26,9 @@
 //! 
 //! この順序により、戦術的に整合性の取れた防衛行動が再現されます。
 //! 
+//! 各ステップで発生した発射・命中・撃破などの状態変化は`SimulationEvent`として
+//! `events`に記録され、登録されたオブザーバー（エクスポーター）に通知されます。
+//! 
 //! ## 使用例
 //! 
 //! ```rust
@@ -43,10 +46,35 @@
 //! engine.run()?;
 //! ```
 
+use std::collections::{HashMap, HashSet};
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::export::ISimulationObserver;
 use crate::models::{Position3D as ModelPosition3D, *};
 use crate::scenario::*;
 use tracing::{info, warn, error, debug, trace};
 
+/// シミュレーションイベント
+/// 
+/// 1ステップ内で発生したエージェントの状態変化を表します。
+/// ステップ終了時にオブザーバーへ通知されます。
+#[derive(Debug, Clone)]
+pub enum SimulationEvent {
+    /// ターゲットが終了した（撃破・突破・領域外）
+    TargetEnded { target_id: String, status: AgentStatus },
+    /// ミサイルが発射された（発射直後の状態）
+    MissileLaunched { launcher_id: String, missile: Missile },
+    /// ミサイルが終了した（終了時点の状態、終了理由は`end_reason`）
+    MissileEnded { missile: Missile },
+    /// 友軍のセンサー・ランチャーが敵の制圧攻撃で無力化された
+    AssetDisabled { asset_id: String, target_id: String },
+}
+
+/// 制圧攻撃（攻撃目標が友軍センサー・ランチャー）の既定の到達判定範囲（メートル）
+pub const DEFAULT_SUPPRESSION_RADIUS_M: f64 = 1000.0;
+
 pub struct SimulationEngine {
     pub current_time: f64,
     pub dt: f64,
@@ -55,13 +83,35 @@ pub struct SimulationEngine {
     pub step_count: u64,
     
     pub command_post: CommandPost,
-    pub sensors: Vec<Sensor>,
+    /// 指揮所以外の防護対象
+    pub defended_assets: Vec<DefendedAsset>,
+    /// センサーとデータ融合（システム航跡）
+    pub sensor_network: SensorNetwork,
     pub launchers: Vec<Launcher>,
     pub targets: Vec<Target>,
     pub missiles: Vec<Missile>,
     
     pub scenario_config: ScenarioConfig,
     pub verbose_level: u8,
+    
+    /// 現在のステップで発生したイベント
+    pub events: Vec<SimulationEvent>,
+    /// シード値から生成する乱数生成器（確率的なモデルで共有）
+    rng: ChaCha8Rng,
+    /// ターゲットIDごとの最初の初回検知イベント（センサーの検知履歴は古いものから削除されるため別途保持）
+    first_detections: HashMap<String, DetectionEvent>,
+    /// デコイ識別の判定を済ませたターゲットID
+    discrimination_checked: HashSet<String>,
+    /// デコイに対して発射したミサイル数
+    missiles_on_decoys: usize,
+    /// 迎撃効果を設定したミサイルの命中ごとの迎撃結果
+    intercept_outcomes: Vec<InterceptOutcome>,
+    /// 指揮所の航跡の推定誤差（真値との比較）
+    track_errors: TrackErrorStats,
+    /// 指揮所への配信を待つセンサー報告（配信時刻順）
+    pending_reports: Vec<SensorReport>,
+    /// 登録されたオブザーバー
+    observers: Vec<Box<dyn ISimulationObserver>>,
 }
 
 impl SimulationEngine {
@@ -82,6 +132,17 @@ impl SimulationEngine {
             scenario.command_post.arrival_radius_m,
         );
         
+        let defended_assets = scenario.defended_assets
+            .iter()
+            .map(|asset| DefendedAsset::new(
+                asset.id.clone(),
+                asset.r#type.clone(),
+                ModelPosition3D::new(asset.position.x_m, asset.position.y_m, 0.0),
+                asset.arrival_radius_m,
+                asset.value,
+            ))
+            .collect();
+        
         Self {
             current_time: 0.0,
             dt,
@@ -89,15 +150,47 @@ impl SimulationEngine {
             seed,
             step_count: 0,
             command_post,
-            sensors: Vec::new(),
+            defended_assets,
+            sensor_network: SensorNetwork::new(),
             launchers: Vec::new(),
             targets: Vec::new(),
             missiles: Vec::new(),
             scenario_config: scenario,
             verbose_level,
+            events: Vec::new(),
+            rng: ChaCha8Rng::seed_from_u64(seed),
+            first_detections: HashMap::new(),
+            discrimination_checked: HashSet::new(),
+            missiles_on_decoys: 0,
+            intercept_outcomes: Vec::new(),
+            track_errors: TrackErrorStats::default(),
+            pending_reports: Vec::new(),
+            observers: Vec::new(),
         }
     }
     
+    /// オブザーバー（エクスポーター等）を登録
+    /// 
+    /// # 引数
+    /// 
+    /// * `observer` - 登録するオブザーバー
+    pub fn add_observer(&mut self, observer: Box<dyn ISimulationObserver>) {
+        self.observers.push(observer);
+    }
+    
+    /// 登録済みの全オブザーバーに通知
+    fn notify_observers<F>(&mut self, mut notify: F) -> std::io::Result<()>
+    where
+        F: FnMut(&mut dyn ISimulationObserver, &SimulationEngine) -> std::io::Result<()>,
+    {
+        let mut observers = std::mem::take(&mut self.observers);
+        let result = observers
+            .iter_mut()
+            .try_for_each(|observer| notify(observer.as_mut(), self));
+        self.observers = observers;
+        result
+    }
+    
     pub fn initialize(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         if self.verbose_level > 0 {
             info!("シミュレーションエンジンを初期化中...");
@@ -111,7 +204,7 @@ impl SimulationEngine {
         if self.verbose_level > 0 {
             info!("初期化完了:");
             info!("  指揮所: 1基");
-            info!("  センサー: {}基", self.sensors.len());
+            info!("  センサー: {}基", self.sensor_network.sensors.len());
             info!("  ランチャー: {}基", self.launchers.len());
             info!("  敵機: {}機", self.targets.len());
         }
@@ -146,6 +239,16 @@ impl SimulationEngine {
             );
             
             sensor.initialize(&self.scenario_config);
+            if let Some(pd) = &sensor_config.detection_probability {
+                let model = DetectionProbability {
+                    reference_snr: 10f64.powf(pd.reference_snr_db / 10.0),
+                    false_alarm_probability: pd.false_alarm_probability,
+                    threshold: pd.threshold,
+                    confirm_hits: pd.confirm_m,
+                    confirm_looks: pd.confirm_n,
+                };
+                sensor.set_detection_probability(model, pd.seed.unwrap_or_else(|| self.rng.r#gen()));
+            }
             
             if self.verbose_level > 1 {
                 debug!("センサー初期化: {} (範囲: {:.0}m)", 
@@ -153,7 +256,17 @@ impl SimulationEngine {
                         sensor_config.range_m);
             }
             
-            self.sensors.push(sensor);
+            self.sensor_network.sensors.push(sensor);
+        }
+        
+        let fusion = &self.scenario_config.friendly_forces.sensor_fusion;
+        self.sensor_network.fusion_enabled = fusion.is_some();
+        if let Some(fusion) = fusion {
+            self.sensor_network.tracker = SystemTracker::new(
+                fusion.gate,
+                fusion.coast_time_s,
+                self.scenario_config.command_post.track_process_noise_mps2,
+            );
         }
         
         Ok(())
@@ -198,6 +311,73 @@ impl SimulationEngine {
                 group_config.z_m,
             );
             
+            // === 攻撃目標の防護対象を決定 ===
+            // 防護対象 → 友軍センサー・ランチャー（制圧攻撃）の順に検索し、
+            // 指定がなければ指揮所（地上レベル）
+            let target_asset = group_config.target_asset.as_deref();
+            let friendly_position = target_asset.and_then(|id| {
+                self.sensor_network.sensors
+                    .iter()
+                    .find(|sensor| sensor.id == id)
+                    .map(|sensor| sensor.position)
+                    .or_else(|| self.launchers.iter().find(|launcher| launcher.id == id).map(|launcher| launcher.position))
+            });
+            let (asset_id, destination, arrival_radius) =
+                match target_asset.and_then(|id| self.defended_assets.iter().find(|asset| asset.id == id)) {
+                    Some(asset) => (asset.id.clone(), asset.position, asset.arrival_radius),
+                    None => match (target_asset, friendly_position) {
+                        (Some(id), Some(position)) => (id.to_string(), position, DEFAULT_SUPPRESSION_RADIUS_M),
+                        _ => (
+                            self.command_post.id.clone(),
+                            self.command_post.position,
+                            self.command_post.arrival_radius,
+                        ),
+                    },
+                };
+            let arrival_radius = group_config.arrival_radius_m.unwrap_or(arrival_radius);
+            
+            // === 編隊形状を決定 ===
+            // 進行方向の省略時は防護対象方向（+X基準・反時計回り）
+            let heading_to_destination = (destination.y - group_center.y)
+                .atan2(destination.x - group_center.x)
+                .to_degrees();
+            let heading = |heading_deg: &Option<f64>| heading_deg.unwrap_or(heading_to_destination);
+            let formation = match &group_config.formation {
+                FormationConfig::Rings => Formation::Rings,
+                FormationConfig::LineAbreast { spacing_m, heading_deg } => {
+                    Formation::LineAbreast { spacing: *spacing_m, heading_deg: heading(heading_deg) }
+                }
+                FormationConfig::Column { spacing_m, heading_deg } => {
+                    Formation::Column { spacing: *spacing_m, heading_deg: heading(heading_deg) }
+                }
+                FormationConfig::Wedge { spacing_m, heading_deg } => {
+                    Formation::Wedge { spacing: *spacing_m, heading_deg: heading(heading_deg) }
+                }
+                FormationConfig::Box { spacing_m, heading_deg, columns } => Formation::Box {
+                    spacing: *spacing_m,
+                    heading_deg: heading(heading_deg),
+                    columns: columns.unwrap_or_else(|| (group_config.count as f64).sqrt().ceil() as u32),
+                },
+                FormationConfig::Scatter { radius_m, seed } => Formation::Scatter {
+                    radius: *radius_m,
+                    seed: seed.unwrap_or_else(|| self.rng.r#gen()),
+                },
+            };
+            
+            // === 弾道ミサイル型の着弾点と弾道形状 ===
+            let ballistic = group_config.ballistic.as_ref().map(|ballistic| {
+                let impact = ballistic
+                    .impact_xy
+                    .as_ref()
+                    .map(|impact| ModelPosition3D::new(impact.x_m, impact.y_m, 0.0))
+                    .unwrap_or(destination);
+                let shape = match (ballistic.apogee_m, ballistic.flight_time_s) {
+                    (Some(apogee), _) => BallisticShape::Apogee(apogee),
+                    (None, flight_time) => BallisticShape::FlightTime(flight_time.unwrap_or_default()),
+                };
+                (impact, shape)
+            });
+            
             // === TargetGroupオブジェクトを構築 ===
             // グループ全体の配置パターン（同心円リング配置）と動作パラメータを設定
             let target_group = TargetGroup {
@@ -207,16 +387,68 @@ impl SimulationEngine {
                 ring_spacing: group_config.ring_spacing_m,     // リング間隔（メートル）
                 start_angle: group_config.start_angle_deg,     // 配置開始角度（度）
                 ring_half_offset: group_config.ring_half_offset, // 外側リングの半角オフセット
+                formation,                                      // 編隊形状
                 endurance: group_config.endurance_pt,          // 各ターゲットの耐久値
                 spawn_time: group_config.spawn_time_s,         // グループ出現時刻（秒）
+                spawn_interval: group_config.spawn_interval_s, // 各機の出現間隔（秒）
+                // 出現時のばらつき（シード省略時はシミュレーションの乱数から決定）
+                spawn_jitter: group_config.spawn_jitter.as_ref().map(|jitter| SpawnJitter {
+                    time: jitter.time_s,
+                    speed: jitter.speed_mps,
+                    position: jitter.position_m,
+                    seed: jitter.seed.unwrap_or_else(|| self.rng.r#gen()),
+                }),
+                // 編隊の広がりやばらつきで出現範囲を外れた機は範囲内に寄せる
+                spawn_bounds: {
+                    let rect = &self.scenario_config.enemy_forces.spawn_rect_xy;
+                    Some((rect.xmin_m, rect.xmax_m, rect.ymin_m, rect.ymax_m))
+                },
                 speed: group_config.speed_mps,                 // 移動速度（m/s）
-                // 全ターゲットの共通目的地（指揮所位置）
-                destination: ModelPosition3D::new(
-                    self.scenario_config.command_post.position.x_m,
-                    self.scenario_config.command_post.position.y_m,
-                    0.0  // 指揮所は地上レベル
-                ),
-                arrival_radius: self.scenario_config.command_post.arrival_radius_m,
+                // 全ターゲットの共通目的地（攻撃目標の防護対象）
+                destination,
+                arrival_radius,
+                asset_id,
+                // 最終進入前の経路点（区間速度の省略時はグループ速度）
+                waypoints: group_config
+                    .waypoints
+                    .iter()
+                    .map(|waypoint| Waypoint {
+                        position: ModelPosition3D::new(waypoint.x_m, waypoint.y_m, waypoint.z_m),
+                        speed: waypoint.speed_mps.unwrap_or(group_config.speed_mps),
+                    })
+                    .collect(),
+                // ミサイル終盤での回避機動
+                maneuver: group_config.maneuver.as_ref().map(|maneuver| ManeuverProfile {
+                    kind: match maneuver.r#type {
+                        ManeuverKind::Jink => ManeuverType::Jink,
+                        ManeuverKind::BarrelRoll => ManeuverType::BarrelRoll,
+                        ManeuverKind::BreakTurn => ManeuverType::BreakTurn,
+                    },
+                    max_g: maneuver.max_g,
+                    duration_s: maneuver.duration_s,
+                    period_s: maneuver.period_s,
+                    trigger_range_m: maneuver.trigger_range_m,
+                }),
+                ballistic,
+                // 巡航ミサイル型の低高度飛行プロファイル
+                cruise: group_config.cruise.as_ref().map(|cruise| CruiseProfile {
+                    cruise_altitude: cruise.altitude_agl_m,
+                    terminal: match cruise.terminal {
+                        TerminalKind::None => TerminalManeuver::None,
+                        TerminalKind::PopUp => TerminalManeuver::PopUp,
+                        TerminalKind::Dive => TerminalManeuver::Dive,
+                    },
+                    terminal_range: cruise.terminal_range_m,
+                    pop_up_altitude: cruise.pop_up_altitude_m,
+                }),
+                // 最終進入時の高度プロファイル
+                altitude_profile: group_config.altitude_profile.as_ref().map(|profile| AltitudeProfile {
+                    cruise_altitude: profile.cruise_altitude_m.unwrap_or(group_config.z_m),
+                    descent_start: profile.descent_start_m,
+                    dive_angle_deg: profile.dive_angle_deg,
+                }),
+                rcs_m2: group_config.signature_rcs_m2(),
+                decoy_ratio: group_config.decoy_ratio,
             };
             
             // === グループ内の個別ターゲット生成 ===
@@ -247,9 +479,18 @@ impl SimulationEngine {
     
     pub fn run(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         info!("=== シミュレーション実行開始 ===");
+        self.notify_observers(|observer, engine| observer.on_start(engine))?;
         
         while self.current_time < self.max_time {
             self.step();
+            match self.notify_observers(|observer, engine| observer.on_step(engine)) {
+                // オブザーバーからの中断要求（TUIでの終了操作など）
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {
+                    info!("シミュレーションが中断されました: {}", e);
+                    break;
+                }
+                result => result?,
+            }
             
             if self.verbose_level > 2 {
                 trace!("時刻: {:.1}秒 (ステップ: {})", self.current_time, self.step_count);
@@ -268,11 +509,200 @@ impl SimulationEngine {
         info!("=== シミュレーション完了 ===");
         info!("実行時間: {:.1}秒", self.current_time);
         info!("総ステップ数: {}", self.step_count);
+        self.print_results();
+        
+        self.notify_observers(|observer, engine| observer.on_finish(engine))?;
         
         Ok(())
     }
     
-    fn step(&mut self) {
+    /// 交戦結果を表示
+    /// 
+    /// ターゲットの最終状態を集計し、全体（デコイは別集計）と防護対象ごとの結果を出力します。
+    pub fn print_results(&self) {
+        // デコイは実目標の集計から除外して別途集計
+        let threats = || self.targets.iter().filter(|t| !t.is_decoy);
+        let count = |status: AgentStatus, asset_id: Option<&str>| {
+            threats()
+                .filter(|t| t.status == status && asset_id.is_none_or(|id| t.asset_id == id))
+                .count()
+        };
+        
+        info!("=== 交戦結果 ===");
+        info!("敵機: {}機 (撃破: {}, 突破: {}, 領域外: {}, 残存: {}, 未出現: {})",
+              threats().count(),
+              count(AgentStatus::Destroyed, None),
+              count(AgentStatus::Reached, None),
+              threats().filter(|t| t.spawned && t.status == AgentStatus::Inactive).count(),
+              count(AgentStatus::Active, None),
+              threats().filter(|t| !t.spawned).count());
+        
+        let decoys: Vec<&Target> = self.targets.iter().filter(|t| t.is_decoy).collect();
+        if !decoys.is_empty() {
+            let arrived = decoys
+                .iter()
+                .filter(|t| t.status == AgentStatus::Inactive && t.position.distance_xy(&t.destination) <= t.arrival_radius)
+                .count();
+            info!("デコイ: {}機 (撃破: {}, 到達: {}, 識別: {}), デコイに消費したミサイル: {}発",
+                  decoys.len(),
+                  decoys.iter().filter(|t| t.status == AgentStatus::Destroyed).count(),
+                  arrived,
+                  decoys.iter().filter(|t| t.decoy_revealed).count(),
+                  self.missiles_on_decoys);
+        }
+        
+        if !self.intercept_outcomes.is_empty() {
+            let outcomes = |outcome: InterceptOutcome| {
+                self.intercept_outcomes.iter().filter(|o| **o == outcome).count()
+            };
+            info!("迎撃判定: {}回 (撃墜: {}, 損傷: {}, 効果なし: {})",
+                  self.intercept_outcomes.len(),
+                  outcomes(InterceptOutcome::Kill),
+                  outcomes(InterceptOutcome::Damage),
+                  outcomes(InterceptOutcome::NoEffect));
+        }
+        
+        if self.track_errors.samples > 0 {
+            info!("=== 航跡推定誤差 ===");
+            info!("位置誤差 RMS {:.1}m (最大 {:.1}m), 速度誤差 RMS {:.1}m/s, 推定位置標準偏差 平均 {:.1}m ({}サンプル)",
+                  self.track_errors.rms_position_error(),
+                  self.track_errors.max_position_error,
+                  self.track_errors.rms_velocity_error(),
+                  self.track_errors.mean_position_sigma(),
+                  self.track_errors.samples);
+        }
+        
+        if self.sensor_network.fusion_enabled {
+            let stats = &self.sensor_network.tracker.stats;
+            info!("=== 航跡融合 ===");
+            info!("システム航跡: 開始 {}, 重複の統合 {}, 観測途絶で消去 {}, 同一ターゲットの重複 最大 {}",
+                  stats.initiated,
+                  stats.merged,
+                  stats.coasted_out,
+                  stats.peak_duplicates);
+            info!("観測: {} (既存航跡への相関 {}, うち誤相関 {})",
+                  stats.measurements,
+                  stats.associated,
+                  stats.misassociated);
+        }
+        
+        let capped_sensors: Vec<&Sensor> = self.sensor_network.sensors.iter().filter(|s| s.max_tracks.is_some()).collect();
+        if !capped_sensors.is_empty() {
+            info!("=== センサー航跡容量 ===");
+            for sensor in capped_sensors {
+                let stats = &sensor.saturation;
+                info!("  {} (容量 {}): 飽和 {}/{}回更新, 航跡破棄 {}, 航跡を開始できなかったターゲット {}, 最大追跡要求 {}",
+                      sensor.id,
+                      sensor.max_tracks.unwrap_or_default(),
+                      stats.saturated_updates,
+                      stats.updates,
+                      stats.dropped_tracks,
+                      stats.rejected_detections,
+                      stats.peak_demand);
+            }
+        }
+        
+        info!("=== 防護対象別結果 ===");
+        let command_post = (
+            self.command_post.id.as_str(),
+            "command_post",
+            self.scenario_config.command_post.value,
+        );
+        let assets = std::iter::once(command_post)
+            .chain(self.defended_assets.iter().map(|a| (a.id.as_str(), a.kind.as_str(), a.value)));
+        let mut lost_value = 0.0;
+        for (asset_id, kind, value) in assets {
+            let attackers = threats().filter(|t| t.asset_id == asset_id).count();
+            if attackers == 0 && asset_id != self.command_post.id {
+                continue;
+            }
+            let breakthroughs = count(AgentStatus::Reached, Some(asset_id));
+            if breakthroughs > 0 {
+                lost_value += value;
+            }
+            info!("  {} ({}, 価値 {:.1}): 攻撃 {}機, 撃破 {}, 突破 {}{}",
+                  asset_id, kind, value, attackers,
+                  count(AgentStatus::Destroyed, Some(asset_id)),
+                  breakthroughs,
+                  if breakthroughs > 0 { " ※突破あり" } else { "" });
+        }
+        info!("被害を受けた防護対象の価値合計: {:.1}", lost_value);
+        
+        // 制圧攻撃を受けた友軍センサー・ランチャー
+        let friendly = self.sensor_network.sensors
+            .iter()
+            .map(|s| (s.id.as_str(), "sensor", s.status))
+            .chain(self.launchers.iter().map(|l| (l.id.as_str(), "launcher", l.status)));
+        for (asset_id, kind, status) in friendly {
+            let attackers = threats().filter(|t| t.asset_id == asset_id).count();
+            if attackers == 0 {
+                continue;
+            }
+            info!("  {} ({}): 攻撃 {}機, 撃破 {}, 突破 {}{}",
+                  asset_id, kind, attackers,
+                  count(AgentStatus::Destroyed, Some(asset_id)),
+                  count(AgentStatus::Reached, Some(asset_id)),
+                  if status == AgentStatus::Destroyed { " ※無力化" } else { "" });
+        }
+        let disabled = |statuses: &mut dyn Iterator<Item = AgentStatus>| {
+            let statuses: Vec<AgentStatus> = statuses.collect();
+            (statuses.iter().filter(|s| **s == AgentStatus::Destroyed).count(), statuses.len())
+        };
+        let (sensors_disabled, sensors_total) = disabled(&mut self.sensor_network.sensors.iter().map(|s| s.status));
+        let (launchers_disabled, launchers_total) = disabled(&mut self.launchers.iter().map(|l| l.status));
+        if sensors_disabled + launchers_disabled > 0 {
+            info!("無力化された友軍: センサー {}/{}基, ランチャー {}/{}基",
+                  sensors_disabled, sensors_total, launchers_disabled, launchers_total);
+        }
+
+        self.print_detection_ranges();
+    }
+
+    /// 信号特性（RCS）別の初探知距離を出力
+    ///
+    /// 各ターゲットについて全センサーで最も早い初回検知イベントの距離を集計します。
+    fn print_detection_ranges(&self) {
+        // RCSごとに（出現数、初探知距離、実効探知範囲）を集計
+        let mut classes: Vec<(f64, usize, Vec<f64>, Vec<f64>)> = Vec::new();
+        for target in self.targets.iter().filter(|t| t.spawned) {
+            let index = match classes.iter().position(|(rcs, ..)| *rcs == target.rcs_m2) {
+                Some(index) => index,
+                None => {
+                    classes.push((target.rcs_m2, 0, Vec::new(), Vec::new()));
+                    classes.len() - 1
+                }
+            };
+            let class = &mut classes[index];
+            class.1 += 1;
+            if let Some(event) = self.first_detections.get(&target.id) {
+                class.2.push(event.distance);
+                class.3.push(event.effective_range);
+            }
+        }
+        classes.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        info!("=== 信号特性別の初探知距離 ===");
+        let mean = |values: &[f64]| values.iter().sum::<f64>() / values.len() as f64;
+        for (rcs, spawned, ranges, effective_ranges) in &classes {
+            if ranges.is_empty() {
+                info!("  RCS {:.2}m²: 出現 {}機, 探知 0機", rcs, spawned);
+                continue;
+            }
+            info!("  RCS {:.2}m²: 出現 {}機, 探知 {}機, 初探知距離 平均 {:.1}km (最小 {:.1}km, 最大 {:.1}km), 実効探知範囲 平均 {:.1}km",
+                  rcs, spawned, ranges.len(),
+                  mean(ranges) / 1000.0,
+                  ranges.iter().copied().fold(f64::INFINITY, f64::min) / 1000.0,
+                  ranges.iter().copied().fold(0.0, f64::max) / 1000.0,
+                  mean(effective_ranges) / 1000.0);
+        }
+    }
+    
+    /// 1ステップ分のシミュレーションを実行
+    /// 
+    /// ステップ内で発生したイベントは`events`に記録されます。
+    pub fn step(&mut self) {
+        self.events.clear();
+        
         self.process_targets();
         self.process_missiles();
         self.process_sensors();
@@ -284,46 +714,273 @@ impl SimulationEngine {
     }
     
     fn process_targets(&mut self) {
+        // 攻撃目標に到達したターゲット（攻撃目標ID, ターゲットID）
+        let mut reached: Vec<(String, String)> = Vec::new();
+        
         for target in &mut self.targets {
-            if target.is_active() && self.current_time >= target.spawn_time {
-                target.tick(self.dt);
+            target.check_spawn(self.current_time);
+            
+            if target.is_active() {
+                target.update_maneuver(self.dt, &mut self.rng);
+                target.move_agent(self.dt);
+                target.check_arrival();
+                target.check_out_of_bounds();
+                
+                if !target.is_active() {
+                    self.command_post.on_target_destroyed(target.id.clone());
+                    self.sensor_network.tracker.remove_target(&target.id);
+                    self.events.push(SimulationEvent::TargetEnded {
+                        target_id: target.id.clone(),
+                        status: target.status,
+                    });
+                    if target.status == AgentStatus::Reached {
+                        reached.push((target.asset_id.clone(), target.id.clone()));
+                    }
+                }
             }
         }
+        
+        for (asset_id, target_id) in reached {
+            self.disable_friendly_asset(&asset_id, &target_id);
+        }
+    }
+    
+    /// 制圧攻撃を受けた友軍センサー・ランチャーを無力化
+    /// 
+    /// 無力化されたセンサーは探知を停止して検知中のターゲットを失い、
+    /// ランチャーは以降の発射を行いません（飛翔中のミサイルは継続）。
+    /// 
+    /// # 引数
+    /// 
+    /// * `asset_id` - 到達したターゲットの攻撃目標ID
+    /// * `target_id` - 到達したターゲットのID
+    fn disable_friendly_asset(&mut self, asset_id: &str, target_id: &str) {
+        if let Some(sensor) = self.sensor_network.sensors.iter_mut().find(|s| s.id == asset_id && s.is_active()) {
+            sensor.status = AgentStatus::Destroyed;
+            sensor.detected_targets.clear();
+            // 配信待ちの報告も失われ、指揮所はこのセンサーからの検知を失う
+            // （データ融合が有効な場合、このセンサーのみが観測していたシステム航跡は観測途絶で消去される）
+            self.pending_reports.retain(|report| report.sensor_id != asset_id);
+            if !self.sensor_network.fusion_enabled {
+                self.command_post.receive_detections(asset_id, Vec::new());
+            }
+        } else if let Some(launcher) = self.launchers.iter_mut().find(|l| l.id == asset_id && l.is_active()) {
+            launcher.status = AgentStatus::Destroyed;
+        } else {
+            return;
+        }
+        warn!(asset_id = %asset_id, target_id = %target_id, "ASSET_DISABLED: 友軍アセットが制圧攻撃で無力化されました");
+        self.events.push(SimulationEvent::AssetDisabled {
+            asset_id: asset_id.to_string(),
+            target_id: target_id.to_string(),
+        });
     }
     
     fn process_missiles(&mut self) {
+        // 命中したターゲットIDと迎撃結果
+        let mut hits: Vec<(String, InterceptOutcome)> = Vec::new();
+        // 回避機動の契機となる脅威（ターゲットID, ミサイルID, ミサイル位置, 距離, 終盤フェーズか）
+        let mut threats: Vec<(String, String, ModelPosition3D, f64, bool)> = Vec::new();
+        
         for missile in &mut self.missiles {
-            if missile.is_active() {
-                missile.tick(self.dt);
+            if !missile.is_active() {
+                continue;
+            }
+            
+            let target_state = self.targets
+                .iter()
+                .find(|t| t.id == missile.target_id && t.is_active())
+                .map(|t| (t.position, t.velocity));
+            
+            match target_state {
+                Some((target_position, target_velocity)) => {
+                    missile.update_kinematics(self.dt, target_position);
+                    missile.perform_checks(target_position);
+                    
+                    if missile.end_reason == Some(MissileEndReason::Hit) {
+                        // 迎撃効果が未設定のミサイルは従来どおり耐久値を1減少
+                        let outcome = match missile.lethality {
+                            Some(lethality) => {
+                                let miss_distance = missile.calculate_miss_distance(target_position);
+                                let closing_speed = Velocity3D::new(
+                                    missile.velocity.x - target_velocity.x,
+                                    missile.velocity.y - target_velocity.y,
+                                    missile.velocity.z - target_velocity.z,
+                                ).magnitude();
+                                let (outcome, kill_probability) = lethality.resolve(
+                                    &mut self.rng,
+                                    miss_distance,
+                                    missile.intercept_radius,
+                                    closing_speed,
+                                );
+                                info!(
+                                    missile_id = %missile.id,
+                                    target_id = %missile.target_id,
+                                    miss_distance = miss_distance,
+                                    closing_speed = closing_speed,
+                                    kill_probability = kill_probability,
+                                    outcome = ?outcome,
+                                    "INTERCEPT_OUTCOME: 迎撃結果を判定しました"
+                                );
+                                self.intercept_outcomes.push(outcome);
+                                outcome
+                            }
+                            None => InterceptOutcome::Damage,
+                        };
+                        hits.push((missile.target_id.clone(), outcome));
+                    } else if missile.is_active() {
+                        threats.push((
+                            missile.target_id.clone(),
+                            missile.id.clone(),
+                            missile.position,
+                            missile.position.distance_3d(&target_position),
+                            missile.guidance_phase == GuidancePhase::Endgame,
+                        ));
+                    }
+                }
+                None => missile.on_target_lost(),
+            }
+        }
+        
+        // 交戦中のミサイルが迫ったターゲットは回避機動を開始
+        for (target_id, missile_id, missile_position, distance, in_endgame) in &threats {
+            if let Some(target) = self.targets.iter_mut().find(|t| &t.id == target_id)
+                && target.should_evade(missile_id, *distance, *in_endgame)
+            {
+                target.start_maneuver(missile_id, missile_position, &mut self.rng);
             }
         }
         
+        // 同一サイクル内の命中は同時適用し、耐久値を一括減算（撃墜は残り耐久値すべて）
+        for target in &mut self.targets {
+            let damage = hits
+                .iter()
+                .filter(|(id, _)| *id == target.id)
+                .map(|(_, outcome)| match outcome {
+                    InterceptOutcome::Kill => target.endurance,
+                    InterceptOutcome::Damage => 1,
+                    InterceptOutcome::NoEffect => 0,
+                })
+                .sum::<u32>()
+                .min(target.endurance);
+            if damage > 0 && target.is_active() {
+                target.take_damage(damage);
+                
+                if !target.is_active() {
+                    self.command_post.on_target_destroyed(target.id.clone());
+                    self.sensor_network.tracker.remove_target(&target.id);
+                    self.events.push(SimulationEvent::TargetEnded {
+                        target_id: target.id.clone(),
+                        status: target.status,
+                    });
+                }
+            }
+        }
+        
+        for missile in self.missiles.iter().filter(|m| !m.is_active()) {
+            self.command_post.on_missile_destroyed(missile.id.clone());
+            self.events.push(SimulationEvent::MissileEnded { missile: missile.clone() });
+        }
+        
         self.missiles.retain(|m| m.is_active());
     }
     
+    /// センサーの探知を更新し、報告遅延を経た報告を指揮所に配信
+    /// 
+    /// 更新周期に達したセンサーのみが探知・観測を行い、その結果を
+    /// 報告遅延後に配信する報告として登録します。
+    /// 配信時刻に達した報告は配信時刻順に、データ融合が有効な場合はセンサーネットワークで
+    /// システム航跡に融合してから、無効な場合はセンサーごとの報告のまま指揮所へ渡されます。
     fn process_sensors(&mut self) {
-        for sensor in &mut self.sensors {
-            if sensor.is_active() {
-                sensor.update_detections(&self.targets, self.current_time);
-                sensor.tick(self.dt);
+        // 航跡容量の優先順位に使う指揮所の目標優先度（前ステップの評価結果）
+        let priority_feedback: Vec<String> = self.command_post.target_priorities
+            .iter()
+            .map(|p| p.target_id.clone())
+            .collect();
+        let reports = self.sensor_network.collect_reports(
+            &self.targets,
+            &mut self.rng,
+            self.current_time,
+            self.dt,
+            &priority_feedback,
+        );
+        
+        // このステップの初回検知を記録（全センサーで最初のもののみ）
+        for sensor in self.sensor_network.sensors.iter().filter(|s| s.is_active()) {
+            for event in sensor.detection_history.iter().rev().take_while(|e| e.timestamp >= self.current_time) {
+                if event.event_type == DetectionEventType::FirstDetected {
+                    self.first_detections.entry(event.target_id.clone()).or_insert_with(|| event.clone());
+                }
+            }
+        }
+        
+        for report in reports {
+            let index = self.pending_reports.partition_point(|r| r.deliver_time <= report.deliver_time);
+            self.pending_reports.insert(index, report);
+        }
+        
+        let delivered = self.pending_reports.partition_point(|r| r.deliver_time <= self.current_time + 1e-9);
+        if self.sensor_network.fusion_enabled {
+            for report in self.pending_reports.drain(..delivered) {
+                self.sensor_network.fuse_report(report);
+            }
+            self.sensor_network.tracker.prune(self.current_time);
+            self.command_post.receive_system_tracks(self.sensor_network.tracker.tracks.clone(), self.current_time);
+        } else {
+            let mut measurements = Vec::new();
+            for report in self.pending_reports.drain(..delivered) {
+                self.command_post.receive_detections(&report.sensor_id, report.detected_targets);
+                measurements.extend(report.measurements);
+            }
+            self.command_post.update_tracks(measurements, self.current_time);
+        }
+    }
+    
+    /// デコイ識別モデルを適用
+    /// 
+    /// 初回検知から識別に必要な追跡時間が経過したデコイについて、
+    /// 1回だけ識別確率で判定し、識別されたデコイを目標優先度の対象外にします。
+    fn discriminate_decoys(&mut self) {
+        let Some(discrimination) = &self.scenario_config.command_post.decoy_discrimination else {
+            return;
+        };
+        for target in &mut self.targets {
+            if !target.is_decoy || !target.is_active() || self.discrimination_checked.contains(&target.id) {
+                continue;
+            }
+            let Some(first_detection) = self.first_detections.get(&target.id) else {
+                continue;
+            };
+            if self.current_time - first_detection.timestamp < discrimination.tracking_time_s {
+                continue;
+            }
+            self.discrimination_checked.insert(target.id.clone());
+            if self.rng.gen_bool(discrimination.probability) {
+                target.decoy_revealed = true;
+                info!(target_id = %target.id, tracking_time = self.current_time - first_detection.timestamp,
+                      "DECOY_DISCRIMINATED: 指揮所がデコイを識別しました");
             }
         }
     }
     
     fn process_command_post(&mut self) {
         if self.command_post.is_active() {
-            let detected_targets: Vec<String> = self.sensors
-                .iter()
-                .flat_map(|s| s.get_detected_targets())
-                .collect();
+            self.discriminate_decoys();
             
+            let detected_targets = &self.command_post.detected_targets;
             let active_targets: Vec<&Target> = self.targets
                 .iter()
-                .filter(|t| t.is_active() && detected_targets.contains(&t.get_id()))
+                .filter(|t| t.is_active() && !t.decoy_revealed && detected_targets.contains(&t.get_id()))
                 .collect();
             
             self.command_post.update_target_list(active_targets);
             self.command_post.tick(self.dt);
+            
+            for (target_id, track) in self.command_post.track_estimates() {
+                if let Some(target) = self.targets.iter().find(|t| t.id == target_id && t.is_active()) {
+                    self.track_errors.record(&track, &target.position, &target.velocity);
+                }
+            }
         }
     }
     
@@ -333,6 +990,14 @@ impl SimulationEngine {
                 if let Some(assignment) = self.command_post.get_missile_assignment(&launcher.get_id()) {
                     if let Some(mut new_missile) = launcher.fire_missile_at_target(&assignment.target_id) {
                         new_missile.initialize(&self.scenario_config);
+                        self.command_post.record_assignment(&assignment.target_id, new_missile.get_id());
+                        if self.targets.iter().any(|t| t.id == assignment.target_id && t.is_decoy) {
+                            self.missiles_on_decoys += 1;
+                        }
+                        self.events.push(SimulationEvent::MissileLaunched {
+                            launcher_id: launcher.get_id(),
+                            missile: new_missile.clone(),
+                        });
                         self.missiles.push(new_missile);
                     }
                 }
@@ -346,4 +1011,43 @@ pub struct MissileAssignment {
     pub launcher_id: String,
     pub target_id: String,
     pub priority: f64,
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// センサーの西200kmに出現する高度100mのターゲット1機の初探知距離
+    fn low_altitude_first_detection(radar_horizon: bool) -> f64 {
+        let mut scenario = ScenarioConfig::from_file("scenarios/scenario_simple_test.yaml").unwrap();
+        scenario.sim.t_max_s = 1000.0;
+        scenario.world.radar_horizon = radar_horizon;
+        scenario.enemy_forces.spawn_rect_xy = RegionRect {
+            xmin_m: 100_000.0,
+            xmax_m: 200_000.0,
+            ymin_m: -400_000.0,
+            ymax_m: -300_000.0,
+        };
+        let group = &mut scenario.enemy_forces.groups[0];
+        group.spawn_time_s = 0.0;
+        group.center_xy = Position2D { x_m: 150_000.0, y_m: -350_000.0 };
+        group.z_m = 100.0;
+        group.count = 1;
+
+        let mut engine = SimulationEngine::new(scenario, 0);
+        engine.initialize().unwrap();
+        while engine.first_detections.is_empty() && engine.current_time < engine.max_time {
+            engine.step();
+        }
+        engine.first_detections.values().next().unwrap().distance
+    }
+
+    #[test]
+    fn test_radar_horizon_delays_low_altitude_first_detection() {
+        // 高度50mのセンサー（探知範囲150km）から高度100mのターゲットの水平線距離は約70km
+        let without_horizon = low_altitude_first_detection(false);
+        let with_horizon = low_altitude_first_detection(true);
+        assert!((without_horizon - 150_000.0).abs() < 1_000.0, "{}", without_horizon);
+        assert!((with_horizon - 70_400.0).abs() < 1_000.0, "{}", with_horizon);
+    }
+}
@@ -0,0 +1,480 @@
+//! # TUI モジュール
+//!
+//! シミュレーション実行中の戦況をターミナル上にリアルタイム表示します。
+//!
+//! シミュレーションエンジンにオブザーバーとして登録され、ステップループから
+//! 駆動されます。各ステップで実時間との同期（再生速度）とキー入力を処理し、
+//! 一定間隔で画面を再描画します。
+//!
+//! ## 表示内容
+//!
+//! - **戦域マップ**: `region_rect`を縮尺表示し、ターゲット・ミサイル・センサー探知範囲・
+//!   ランチャー・指揮所を描画
+//! - **目標優先度**: `CommandPost::target_priorities`（Tgo順）
+//! - **ランチャー状態**: 残弾数・クールダウン
+//! - **イベント**: 直近の発射・命中・撃破・突破
+//!
+//! ## キー操作
+//!
+//! - `Space` / `p`: 一時停止・再開
+//! - `+` / `-`: 再生速度の変更
+//! - `q` / `Esc` / `Ctrl+C`: 中断
+
+use std::collections::VecDeque;
+use std::io::{Stdout, Write, stdout};
+use std::time::{Duration, Instant};
+
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEventKind, KeyModifiers},
+    execute, queue,
+    style::{Color, Print, ResetColor, SetForegroundColor},
+    terminal::{self, ClearType},
+};
+
+use crate::export::ISimulationObserver;
+use crate::models::{AgentStatus, IAgent, IPlatform, MissileEndReason};
+use crate::simulation::{SimulationEngine, SimulationEvent};
+
+/// 再生速度の選択肢（シミュレーション時間 / 実時間）。`None`は待ち時間なし
+const SPEEDS: [Option<f64>; 9] = [
+    Some(0.5), Some(1.0), Some(2.0), Some(5.0), Some(10.0), Some(20.0), Some(50.0), Some(100.0), None,
+];
+/// 初期再生速度のインデックス（10倍速）
+const DEFAULT_SPEED_INDEX: usize = 4;
+/// 画面の最小再描画間隔
+const FRAME_INTERVAL: Duration = Duration::from_millis(50);
+/// 右側パネルの幅（桁）
+const PANEL_WIDTH: u16 = 46;
+/// 保持するイベント数
+const MAX_EVENTS: usize = 64;
+
+/// 地図上の描画セル
+#[derive(Clone, Copy)]
+struct Cell {
+    symbol: char,
+    color: Color,
+}
+
+const EMPTY_CELL: Cell = Cell { symbol: ' ', color: Color::Reset };
+
+/// 全角文字を2桁として表示幅を計算
+fn display_width(c: char) -> usize {
+    if c >= '\u{1100}' && (c <= '\u{115F}' || ('\u{2E80}'..='\u{A4CF}').contains(&c)
+        || ('\u{AC00}'..='\u{D7A3}').contains(&c) || ('\u{F900}'..='\u{FAFF}').contains(&c)
+        || ('\u{FF00}'..='\u{FF60}').contains(&c) || ('\u{FFE0}'..='\u{FFE6}').contains(&c))
+    {
+        2
+    } else {
+        1
+    }
+}
+
+/// 表示幅に収まるように文字列を切り詰め
+fn truncate_to_width(text: &str, width: usize) -> String {
+    let mut used = 0;
+    text.chars()
+        .take_while(|c| {
+            used += display_width(*c);
+            used <= width
+        })
+        .collect()
+}
+
+/// ターミナルUIオブザーバー
+pub struct TuiObserver {
+    stdout: Stdout,
+    active: bool,
+    paused: bool,
+    quit_requested: bool,
+    speed_index: usize,
+    last_frame: Instant,
+    last_step_wall: Instant,
+    /// 直近のイベント（時刻, 色, 内容）
+    events: VecDeque<(f64, Color, String)>,
+}
+
+impl TuiObserver {
+    /// 新しいTUIオブザーバーを作成
+    pub fn new() -> Self {
+        Self {
+            stdout: stdout(),
+            active: false,
+            paused: false,
+            quit_requested: false,
+            speed_index: DEFAULT_SPEED_INDEX,
+            last_frame: Instant::now(),
+            last_step_wall: Instant::now(),
+            events: VecDeque::new(),
+        }
+    }
+
+    /// 端末をTUIモード（代替画面・rawモード）に切り替え
+    fn enter(&mut self) -> std::io::Result<()> {
+        terminal::enable_raw_mode()?;
+        execute!(self.stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
+        self.active = true;
+        Ok(())
+    }
+
+    /// 端末を元の状態に復帰
+    fn leave(&mut self) -> std::io::Result<()> {
+        if self.active {
+            self.active = false;
+            execute!(self.stdout, ResetColor, cursor::Show, terminal::LeaveAlternateScreen)?;
+            terminal::disable_raw_mode()?;
+        }
+        Ok(())
+    }
+
+    fn push_event(&mut self, time_s: f64, color: Color, text: String) {
+        self.events.push_front((time_s, color, text));
+        self.events.truncate(MAX_EVENTS);
+    }
+
+    /// ステップのイベントを表示用に記録
+    fn collect_events(&mut self, engine: &SimulationEngine) {
+        for event in &engine.events {
+            let (color, text) = match event {
+                SimulationEvent::MissileLaunched { launcher_id, missile } => {
+                    (Color::Cyan, format!("発射 {} → {}", launcher_id, missile.target_id))
+                }
+                SimulationEvent::MissileEnded { missile } => {
+                    let outcome = match missile.end_reason {
+                        Some(MissileEndReason::Hit) => "命中",
+                        Some(MissileEndReason::SelfDestruct) => "自爆",
+                        Some(MissileEndReason::TargetLost) => "目標消失",
+                        Some(MissileEndReason::OutOfBounds) => "領域外",
+                        None => "終了",
+                    };
+                    (Color::Blue, format!("{} {}", outcome, missile.id))
+                }
+                SimulationEvent::TargetEnded { target_id, status } => match status {
+                    AgentStatus::Destroyed => (Color::Green, format!("撃破 {}", target_id)),
+                    AgentStatus::Reached => (Color::Red, format!("突破 {}", target_id)),
+                    _ => (Color::DarkYellow, format!("離脱 {}", target_id)),
+                },
+                SimulationEvent::AssetDisabled { asset_id, target_id } => {
+                    (Color::Magenta, format!("無力化 {} ← {}", asset_id, target_id))
+                }
+            };
+            self.push_event(engine.current_time, color, text);
+        }
+    }
+
+    /// キー入力の処理
+    ///
+    /// # 引数
+    ///
+    /// * `timeout` - 入力待ちの最大時間
+    ///
+    /// # 戻り値
+    ///
+    /// 中断が要求された場合はtrue
+    fn handle_input(&mut self, timeout: Duration) -> std::io::Result<bool> {
+        if !event::poll(timeout)? {
+            return Ok(false);
+        }
+        if let Event::Key(key) = event::read()? {
+            if key.kind != KeyEventKind::Press {
+                return Ok(false);
+            }
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(true),
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return Ok(true),
+                KeyCode::Char(' ') | KeyCode::Char('p') => self.paused = !self.paused,
+                KeyCode::Char('+') | KeyCode::Char('=') => {
+                    self.speed_index = (self.speed_index + 1).min(SPEEDS.len() - 1);
+                }
+                KeyCode::Char('-') => self.speed_index = self.speed_index.saturating_sub(1),
+                _ => {}
+            }
+        }
+        Ok(false)
+    }
+
+    fn speed_label(&self) -> String {
+        match SPEEDS[self.speed_index] {
+            Some(speed) => format!("x{}", speed),
+            None => "最大".to_string(),
+        }
+    }
+
+    /// 戦域マップのセルを作成
+    fn build_map(engine: &SimulationEngine, width: usize, height: usize) -> Vec<Vec<Cell>> {
+        let mut grid = vec![vec![EMPTY_CELL; width]; height];
+        let region = &engine.scenario_config.world.region_rect;
+        let to_cell = |x: f64, y: f64| -> Option<(usize, usize)> {
+            let col = (x - region.xmin_m) / (region.xmax_m - region.xmin_m) * width as f64;
+            let row = (region.ymax_m - y) / (region.ymax_m - region.ymin_m) * height as f64;
+            (col >= 0.0 && row >= 0.0 && col < width as f64 && row < height as f64)
+                .then_some((col as usize, row as usize))
+        };
+        let mut plot = |x: f64, y: f64, symbol: char, color: Color| {
+            if let Some((col, row)) = to_cell(x, y) {
+                grid[row][col] = Cell { symbol, color };
+            }
+        };
+
+        // 円（探知範囲・到達判定範囲）は円周上の点で描画
+        let ring = |plot: &mut dyn FnMut(f64, f64, char, Color), cx: f64, cy: f64, radius: f64, symbol: char, color: Color| {
+            let points = 4 * (width + height);
+            for i in 0..points {
+                let angle = 2.0 * std::f64::consts::PI * i as f64 / points as f64;
+                plot(cx + radius * angle.cos(), cy + radius * angle.sin(), symbol, color);
+            }
+        };
+
+        // 視野を持つセンサーは視野の方位範囲のみ描画
+        for sensor in engine.sensor_network.sensors.iter().filter(|s| s.is_operational()) {
+            let points = 4 * (width + height);
+            for i in 0..points {
+                let angle = 2.0 * std::f64::consts::PI * i as f64 / points as f64;
+                if sensor.covers_azimuth(angle.to_degrees()) {
+                    plot(
+                        sensor.position.x + sensor.detection_range * angle.cos(),
+                        sensor.position.y + sensor.detection_range * angle.sin(),
+                        '·',
+                        Color::DarkGreen,
+                    );
+                }
+            }
+        }
+        let cp = &engine.command_post;
+        ring(&mut plot, cp.position.x, cp.position.y, cp.arrival_radius, '·', Color::Yellow);
+        for asset in &engine.defended_assets {
+            ring(&mut plot, asset.position.x, asset.position.y, asset.arrival_radius, '·', Color::DarkYellow);
+        }
+        for sensor in &engine.sensor_network.sensors {
+            let color = if sensor.status == AgentStatus::Active { Color::Green } else { Color::DarkGrey };
+            plot(sensor.position.x, sensor.position.y, 'S', color);
+        }
+        for launcher in &engine.launchers {
+            let color = if launcher.can_launch() { Color::Blue } else { Color::DarkBlue };
+            plot(launcher.position.x, launcher.position.y, 'L', color);
+        }
+        for asset in &engine.defended_assets {
+            plot(asset.position.x, asset.position.y, '#', Color::DarkYellow);
+        }
+        plot(cp.position.x, cp.position.y, '@', Color::Yellow);
+        for target in engine.targets.iter().filter(|t| t.is_active()) {
+            plot(target.position.x, target.position.y, 'x', Color::Red);
+        }
+        for missile in engine.missiles.iter().filter(|m| m.is_active()) {
+            plot(missile.position.x, missile.position.y, '*', Color::Cyan);
+        }
+
+        grid
+    }
+
+    /// パネル1行を出力（右端で切り詰め）
+    fn panel_line(&mut self, column: u16, row: u16, color: Color, text: &str) -> std::io::Result<()> {
+        queue!(
+            self.stdout,
+            cursor::MoveTo(column, row),
+            SetForegroundColor(color),
+            Print(truncate_to_width(text, PANEL_WIDTH as usize - 1)),
+            ResetColor,
+            terminal::Clear(ClearType::UntilNewLine),
+        )
+    }
+
+    /// 画面全体の描画
+    fn render(&mut self, engine: &SimulationEngine) -> std::io::Result<()> {
+        let (columns, rows) = terminal::size()?;
+        let map_width = columns.saturating_sub(PANEL_WIDTH + 1).max(10) as usize;
+        let map_height = rows.saturating_sub(2).max(5) as usize;
+
+        // 戦域マップ
+        let grid = Self::build_map(engine, map_width, map_height);
+        for (row, cells) in grid.iter().enumerate() {
+            queue!(self.stdout, cursor::MoveTo(0, row as u16))?;
+            let mut current = Color::Reset;
+            for cell in cells {
+                if cell.color != current {
+                    queue!(self.stdout, SetForegroundColor(cell.color))?;
+                    current = cell.color;
+                }
+                queue!(self.stdout, Print(cell.symbol))?;
+            }
+            queue!(self.stdout, ResetColor, Print('|'))?;
+        }
+
+        // 右側パネル
+        let column = map_width as u16 + 2;
+        let mut row = 0u16;
+        let max_row = rows.saturating_sub(2);
+        let status = if self.paused { "一時停止中" } else { "実行中" };
+        let header = format!(
+            "t={:.1}/{:.0}s 速度{} {}",
+            engine.current_time, engine.max_time, self.speed_label(), status
+        );
+        self.panel_line(column, row, Color::White, &header)?;
+        row += 1;
+        let active_targets = engine.targets.iter().filter(|t| t.is_active()).count();
+        let active_missiles = engine.missiles.iter().filter(|m| m.is_active()).count();
+        self.panel_line(column, row, Color::Grey, &format!("ターゲット {}  ミサイル {}", active_targets, active_missiles))?;
+        row += 2;
+
+        self.panel_line(column, row, Color::Yellow, "■ 目標優先度 (Tgo)")?;
+        row += 1;
+        let priority_rows = ((max_row.saturating_sub(row)) / 3).max(3) as usize;
+        let priorities: Vec<String> = engine.command_post.target_priorities
+            .iter()
+            .take(priority_rows)
+            .map(|p| format!(
+                "{:<16} {:>6.1}s {:>6.1}km {}/{} {}",
+                p.target_id, p.tgo, p.distance_xy / 1000.0, p.assigned_missiles, p.target_endurance, p.asset_id
+            ))
+            .collect();
+        if priorities.is_empty() {
+            self.panel_line(column, row, Color::DarkGrey, "(探知目標なし)")?;
+            row += 1;
+        }
+        for line in priorities {
+            if row >= max_row { break; }
+            self.panel_line(column, row, Color::White, &line)?;
+            row += 1;
+        }
+        row += 1;
+
+        self.panel_line(column, row, Color::Blue, "■ ランチャー")?;
+        row += 1;
+        let launchers: Vec<(Color, String)> = engine.launchers
+            .iter()
+            .map(|l| {
+                let state = if l.status != AgentStatus::Active {
+                    "喪失".to_string()
+                } else if l.current_missiles == 0 {
+                    "弾切れ".to_string()
+                } else if l.cooldown_remaining > 0.0 {
+                    format!("冷却 {:.1}s", l.cooldown_remaining)
+                } else {
+                    "READY".to_string()
+                };
+                let color = if l.can_launch() { Color::Green } else { Color::DarkGrey };
+                (color, format!("{:<16} {:>3}/{:<3} {}", l.id, l.current_missiles, l.max_missiles, state))
+            })
+            .collect();
+        for (color, line) in launchers {
+            if row >= max_row { break; }
+            self.panel_line(column, row, color, &line)?;
+            row += 1;
+        }
+        row += 1;
+
+        if row < max_row {
+            self.panel_line(column, row, Color::Cyan, "■ イベント")?;
+            row += 1;
+        }
+        let events: Vec<(Color, String)> = self.events
+            .iter()
+            .map(|(time_s, color, text)| (*color, format!("{:>7.1}s {}", time_s, text)))
+            .collect();
+        for (color, line) in events {
+            if row >= max_row { break; }
+            self.panel_line(column, row, color, &line)?;
+            row += 1;
+        }
+        while row < max_row {
+            self.panel_line(column, row, Color::Reset, "")?;
+            row += 1;
+        }
+
+        // 凡例・キー操作
+        let footer = "x:ターゲット *:ミサイル S:センサー L:ランチャー @:指揮所 #:防護対象   [Space]一時停止 [+/-]速度 [q]終了";
+        queue!(
+            self.stdout,
+            cursor::MoveTo(0, rows.saturating_sub(1)),
+            SetForegroundColor(Color::DarkGrey),
+            Print(truncate_to_width(footer, columns as usize - 1)),
+            ResetColor,
+            terminal::Clear(ClearType::UntilNewLine),
+        )?;
+
+        self.stdout.flush()?;
+        self.last_frame = Instant::now();
+        Ok(())
+    }
+
+    /// 中断要求をエンジンに伝えるエラー
+    fn interrupted(&mut self) -> std::io::Error {
+        self.quit_requested = true;
+        std::io::Error::new(std::io::ErrorKind::Interrupted, "ユーザー操作により中断されました")
+    }
+}
+
+impl ISimulationObserver for TuiObserver {
+    fn on_start(&mut self, engine: &SimulationEngine) -> std::io::Result<()> {
+        self.enter()?;
+        execute!(self.stdout, terminal::Clear(ClearType::All))?;
+        self.last_step_wall = Instant::now();
+        self.render(engine)
+    }
+
+    fn on_step(&mut self, engine: &SimulationEngine) -> std::io::Result<()> {
+        self.collect_events(engine);
+
+        // 一時停止中は入力待ちのみ
+        while self.paused {
+            self.render(engine)?;
+            if self.handle_input(Duration::from_millis(100))? {
+                return Err(self.interrupted());
+            }
+            self.last_step_wall = Instant::now();
+        }
+
+        // 再生速度に合わせて実時間と同期
+        let wait = SPEEDS[self.speed_index]
+            .map(|speed| Duration::from_secs_f64(engine.dt / speed))
+            .unwrap_or(Duration::ZERO)
+            .saturating_sub(self.last_step_wall.elapsed());
+        if self.handle_input(wait)? {
+            return Err(self.interrupted());
+        }
+        self.last_step_wall = Instant::now();
+
+        if self.last_frame.elapsed() >= FRAME_INTERVAL || !engine.events.is_empty() {
+            self.render(engine)?;
+        }
+        Ok(())
+    }
+
+    fn on_finish(&mut self, engine: &SimulationEngine) -> std::io::Result<()> {
+        if self.quit_requested {
+            return self.leave();
+        }
+        self.push_event(engine.current_time, Color::White, "シミュレーション終了 (任意のキーで閉じる)".to_string());
+        self.render(engine)?;
+        // 最終状態を確認できるようキー入力まで表示を保持
+        if self.active {
+            while !matches!(event::read()?, Event::Key(key) if key.kind == KeyEventKind::Press) {}
+        }
+        self.leave()
+    }
+}
+
+impl Default for TuiObserver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for TuiObserver {
+    fn drop(&mut self) {
+        // エラー・中断時も端末を必ず復帰させる
+        let _ = self.leave();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_to_width() {
+        assert_eq!(truncate_to_width("abcdef", 4), "abcd");
+        assert_eq!(truncate_to_width("目標優先度", 5), "目標");
+        assert_eq!(truncate_to_width("L001 READY", 20), "L001 READY");
+    }
+}